@@ -45,9 +45,9 @@ fn main() {
         x.copy_within(1..PRINT_LEN, 0);
         y.copy_within(1..PRINT_LEN, 0);
         z.copy_within(1..PRINT_LEN, 0);
-        x[PRINT_LEN - 1] = (0., x_val as f32);
-        y[PRINT_LEN - 1] = (0., y_val as f32);
-        z[PRINT_LEN - 1] = (0., z_val as f32);
+        x[PRINT_LEN - 1] = (0., x_val);
+        y[PRINT_LEN - 1] = (0., y_val);
+        z[PRINT_LEN - 1] = (0., z_val);
         for index in 0..PRINT_LEN {
             x[index].0 += 1.;
             y[index].0 += 1.;
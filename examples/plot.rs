@@ -7,7 +7,7 @@ fn main() {
         .lineplot(&Shape::Continuous(Box::new(|x| x.atan())))
         .display();
 
-    // The plot try to display everything that is a `normal` float, skipping NaN's and friends.
+    // The plot try to display everything that is a finite float, skipping NaN's and friends.
     println!("\ny = sin(x) / x");
     Chart::default()
         .lineplot(&Shape::Continuous(Box::new(|x| x.sin() / x)))
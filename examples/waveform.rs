@@ -0,0 +1,33 @@
+use textplots::utils::waveform;
+use textplots::{Chart, Plot, SamplingBuilder, Shape, TextBuilder};
+
+const SAMPLE_RATE: f32 = 44_100.0;
+
+fn main() {
+    // two seconds of interleaved stereo PCM, the way audio libraries hand it out:
+    // a 440 Hz tone fading in on the left, and beating against 443 Hz on the right
+    let frames = 2 * SAMPLE_RATE as usize;
+    let pcm: Vec<f32> = (0..frames)
+        .flat_map(|i| {
+            let t = i as f32 / SAMPLE_RATE;
+            let tone = |frequency: f32| (2.0 * std::f32::consts::PI * frequency * t).sin();
+            [
+                0.8 * tone(440.0) * t / 2.0,
+                0.4 * (tone(440.0) + tone(443.0)),
+            ]
+        })
+        .collect();
+
+    let left: Vec<f32> = pcm.iter().step_by(2).copied().collect();
+    let right: Vec<f32> = pcm.iter().skip(1).step_by(2).copied().collect();
+
+    let duration = frames as f32 / SAMPLE_RATE;
+    for (name, channel) in [("left", &left), ("right", &right)] {
+        let points = waveform(channel, SAMPLE_RATE);
+        Chart::new_with_y_range(200, 32, 0.0, duration, -1.0, 1.0)
+            .lineplot(&Shape::Lines(&points))
+            .envelope(true)
+            .caption(name)
+            .display();
+    }
+}
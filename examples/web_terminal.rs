@@ -0,0 +1,27 @@
+//! Renders animation frames as strings ready to be written to a web terminal like xterm.js,
+//! e.g. from a `wasm32-unknown-unknown` build where there is no stdout to print to.
+
+use textplots::{Chart, ColorPlot, Shape};
+
+const BLUE: rgb::RGB8 = rgb::RGB8::new(0x40, 0x80, 0xFF);
+
+/// Renders a single frame: moves the cursor home and uses CRLF line endings,
+/// as web terminals don't translate bare line feeds.
+fn frame(phase: f32) -> String {
+    let rendered = Chart::new_with_y_range(120, 40, 0.0, 10.0, -1.0, 1.0)
+        .linecolorplot(&Shape::Continuous(Box::new(|x| (x + phase).sin())), BLUE)
+        .render();
+
+    format!("\x1b[H{}", rendered.replace('\n', "\r\n"))
+}
+
+fn main() {
+    // there is no terminal to detect colors support from, so enable them explicitly
+    colored::control::set_override(true);
+
+    // in the browser each frame would be passed to `terminal.write(...)` instead
+    for step in 0..3 {
+        let frame = frame(step as f32 * 0.5);
+        print!("{}", frame);
+    }
+}
@@ -0,0 +1,316 @@
+//! Redrawing charts in place, for live dashboards and simulations.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use textplots::animation::Animation;
+//! use textplots::{Chart, Plot, Shape};
+//!
+//! let mut animation = Animation::new();
+//! animation
+//!     .interval(Duration::from_millis(1))
+//!     .max_frames(10)
+//!     .record(true)
+//!     .output(std::io::sink())
+//!     .run(|frame| {
+//!         let phase = frame as f32 / 5.0;
+//!         let wave = Shape::Continuous(Box::new(move |x| (x + phase).sin()));
+//!         Some(Chart::new(120, 60, -5.0, 5.0).lineplot(&wave).render())
+//!     })
+//!     .unwrap();
+//!
+//! assert_eq!(animation.frames().len(), 10);
+//! ```
+
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Hides the cursor.
+const HIDE_CURSOR: &str = "\x1b[?25l";
+/// Shows the cursor.
+const SHOW_CURSOR: &str = "\x1b[?25h";
+/// Clears the screen and moves the cursor to the top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+/// Moves the cursor to the top left corner.
+const CURSOR_HOME: &str = "\x1b[H";
+
+/// Recorded frame of an animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    /// Time since the first frame was drawn.
+    pub time: Duration,
+    /// Text of the frame as it was printed, without cursor movements.
+    pub text: String,
+}
+
+/// Draws frames over each other at a steady pace, with the cursor hidden in the meantime.
+///
+/// The cursor is shown again when the animation ends, even if producing a frame panics.
+pub struct Animation {
+    /// Time between the starts of consecutive frames.
+    interval: Duration,
+    /// Number of frames after which the animation ends, if any.
+    max_frames: Option<usize>,
+    /// Whether the printed frames are kept.
+    record: bool,
+    frames: Vec<Frame>,
+    output: Box<dyn Write>,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Animation {
+    /// Creates a new `Animation` drawing to the standard output at 10 frames per second.
+    pub fn new() -> Self {
+        Self {
+            interval: Duration::from_millis(100),
+            max_frames: None,
+            record: false,
+            frames: Vec::new(),
+            output: Box::new(io::stdout()),
+        }
+    }
+
+    /// Specifies the time between the starts of consecutive frames. If producing a frame
+    /// takes longer, the next one is drawn right away.
+    pub fn interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Specifies the number of frames per second, same as `interval` of its inverse.
+    pub fn fps(&mut self, fps: f32) -> &mut Self {
+        self.interval = Duration::from_secs_f32(1.0 / fps.max(f32::EPSILON));
+        self
+    }
+
+    /// Ends the animation after the given number of frames.
+    pub fn max_frames(&mut self, frames: usize) -> &mut Self {
+        self.max_frames = Some(frames);
+        self
+    }
+
+    /// Specifies whether the printed frames are kept for replay, see `frames` and `write_cast`.
+    pub fn record(&mut self, enabled: bool) -> &mut Self {
+        self.record = enabled;
+        self
+    }
+
+    /// Specifies where the frames are drawn, the standard output by default.
+    pub fn output(&mut self, output: impl Write + 'static) -> &mut Self {
+        self.output = Box::new(output);
+        self
+    }
+
+    /// Draws the frames returned by the closure, which is given the index of the frame,
+    /// until it returns `None` or the maximum number of frames is reached.
+    ///
+    /// Frames are usually produced with `Chart::render`, but any displayable value will do.
+    pub fn run<F, T>(&mut self, mut frame: F) -> io::Result<&mut Self>
+    where
+        F: FnMut(usize) -> Option<T>,
+        T: Display,
+    {
+        let guard = CursorGuard::new(&mut self.output)?;
+        let start = Instant::now();
+        let mut index = 0;
+
+        while self.max_frames.is_none_or(|max| index < max) {
+            let due = start + self.interval * index as u32;
+            if let Some(wait) = due.checked_duration_since(Instant::now()) {
+                thread::sleep(wait);
+            }
+
+            let Some(content) = frame(index) else {
+                break;
+            };
+            let text = content.to_string();
+
+            // clear what's left of every line, so that shorter frames don't leave traces behind
+            let mut screen = String::with_capacity(text.len() + 64);
+            screen.push_str(CURSOR_HOME);
+            for line in text.lines() {
+                screen.push_str(line);
+                screen.push_str("\x1b[K\n");
+            }
+            screen.push_str("\x1b[J");
+            guard.output.write_all(screen.as_bytes())?;
+            guard.output.flush()?;
+
+            if self.record {
+                self.frames.push(Frame {
+                    time: start.elapsed(),
+                    text,
+                });
+            }
+            index += 1;
+        }
+
+        drop(guard);
+        Ok(self)
+    }
+
+    /// Returns the recorded frames.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Writes the recorded frames as an animated GIF looping forever, each character
+    /// taking 8x16 pixels and colors being kept.
+    ///
+    /// ```rust
+    /// use textplots::animation::Animation;
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let mut animation = Animation::new();
+    /// animation
+    ///     .fps(1000.0)
+    ///     .max_frames(3)
+    ///     .record(true)
+    ///     .output(std::io::sink())
+    ///     .run(|frame| {
+    ///         let line = Shape::Continuous(Box::new(move |x| x * frame as f32));
+    ///         Some(Chart::new(40, 20, 0.0, 1.0).lineplot(&line).render())
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let mut gif = Vec::new();
+    /// animation.write_gif(&mut gif).unwrap();
+    /// assert!(gif.starts_with(b"GIF89a"));
+    /// ```
+    #[cfg(feature = "gif")]
+    pub fn write_gif(&self, writer: impl Write) -> io::Result<()> {
+        use crate::raster::{Raster, CELL_HEIGHT, CELL_WIDTH};
+
+        // keep the image size within the limits of the format
+        let (columns, lines) = self.frame_size();
+        let columns = columns.clamp(1, u16::MAX as usize / CELL_WIDTH);
+        let lines = lines.clamp(1, u16::MAX as usize / CELL_HEIGHT);
+        let width = (columns * CELL_WIDTH) as u16;
+        let height = (lines * CELL_HEIGHT) as u16;
+
+        let mut encoder =
+            gif::Encoder::new(writer, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for (k, frame) in self.frames.iter().enumerate() {
+            let raster = Raster::new(&frame.text, columns, lines);
+            let mut image = gif::Frame::from_rgb(width, height, &raster.pixels);
+
+            // frames are shown until the next one was drawn, the last one for an interval
+            let duration = match self.frames.get(k + 1) {
+                Some(next) => next.time.saturating_sub(frame.time),
+                None => self.interval,
+            };
+            image.delay = (duration.as_millis() / 10).clamp(2, u16::MAX as u128) as u16;
+            encoder.write_frame(&image).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of columns and lines fitting the largest recorded frame.
+    fn frame_size(&self) -> (usize, usize) {
+        let columns = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.text.lines())
+            .map(crate::visible_width)
+            .max()
+            .unwrap_or(0);
+        let lines = self
+            .frames
+            .iter()
+            .map(|frame| frame.text.lines().count())
+            .max()
+            .unwrap_or(0);
+        (columns, lines)
+    }
+
+    /// Writes the recorded frames as an [asciinema](https://asciinema.org) cast file,
+    /// sized to fit the largest frame.
+    ///
+    /// ```rust
+    /// use textplots::animation::Animation;
+    ///
+    /// let mut animation = Animation::new();
+    /// animation
+    ///     .fps(1000.0)
+    ///     .record(true)
+    ///     .output(std::io::sink())
+    ///     .run(|frame| (frame < 3).then(|| format!("frame {}", frame)))
+    ///     .unwrap();
+    ///
+    /// let mut cast = Vec::new();
+    /// animation.write_cast(&mut cast).unwrap();
+    /// let cast = String::from_utf8(cast).unwrap();
+    ///
+    /// assert!(cast.starts_with(r#"{"version": 2, "width": 7, "height": 1}"#));
+    /// assert_eq!(cast.lines().count(), 4);
+    /// ```
+    pub fn write_cast(&self, mut writer: impl Write) -> io::Result<()> {
+        let (width, height) = self.frame_size();
+
+        writeln!(
+            writer,
+            r#"{{"version": 2, "width": {}, "height": {}}}"#,
+            width, height
+        )?;
+
+        for (k, frame) in self.frames.iter().enumerate() {
+            let mut data = String::from(if k == 0 { CLEAR_SCREEN } else { CURSOR_HOME });
+            data.push_str(&frame.text.replace('\n', "\r\n"));
+            writeln!(
+                writer,
+                r#"[{:.6}, "o", "{}"]"#,
+                frame.time.as_secs_f64(),
+                json_escape(&data)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Hides the cursor while alive and shows it again when dropped.
+struct CursorGuard<'w> {
+    output: &'w mut Box<dyn Write>,
+}
+
+impl<'w> CursorGuard<'w> {
+    fn new(output: &'w mut Box<dyn Write>) -> io::Result<Self> {
+        write!(output, "{}{}", HIDE_CURSOR, CLEAR_SCREEN)?;
+        Ok(Self { output })
+    }
+}
+
+impl Drop for CursorGuard<'_> {
+    fn drop(&mut self) {
+        let _ = write!(self.output, "{}", SHOW_CURSOR);
+        let _ = self.output.flush();
+    }
+}
+
+/// Escapes the text to be used within a JSON string.
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,202 @@
+//! [plotters](https://crates.io/crates/plotters) drawing backend rendering to a braille canvas,
+//! available with the `plotters` feature.
+//!
+//! Every backend pixel is a braille dot, so a backend of 120x60 pixels takes 60x15 characters.
+//! Pixels drawn with the background color are cleared, black ones are drawn without a color,
+//! and the rest keep their color. Text is printed with one character per two pixels.
+//!
+//! ```rust
+//! use plotters::prelude::*;
+//! use textplots::backend::BrailleBackend;
+//!
+//! let root = BrailleBackend::new(120, 60).into_drawing_area();
+//! root.fill(&WHITE).unwrap();
+//!
+//! let mut chart = ChartBuilder::on(&root)
+//!     .build_cartesian_2d(-3.14f32..3.14f32, -1.2f32..1.2f32)
+//!     .unwrap();
+//! chart
+//!     .draw_series(LineSeries::new(
+//!         (-314..=314).map(|x| x as f32 / 100.0).map(|x| (x, x.sin())),
+//!         &RED,
+//!     ))
+//!     .unwrap();
+//!
+//! root.present().unwrap();
+//! ```
+
+use crate::rgb_to_pixelcolor;
+use drawille::Canvas as BrailleCanvas;
+use plotters_backend::text_anchor::{HPos, VPos};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+use rgb::RGB8;
+use std::fmt::{Display, Formatter, Result};
+use std::io::{self, Write};
+
+/// Drawing backend with a braille dot for every pixel, printing the canvas when presented.
+pub struct BrailleBackend {
+    /// Canvas width in points.
+    width: u32,
+    /// Canvas height in points.
+    height: u32,
+    canvas: BrailleCanvas,
+    /// Color treated as the absence of dots.
+    background: RGB8,
+    output: Box<dyn Write>,
+}
+
+impl BrailleBackend {
+    /// Creates a new `BrailleBackend` of the given size in points, printing to the standard output
+    /// on a white background.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            // the canvas spans the points up to its size inclusive, so leave out the last one
+            canvas: BrailleCanvas::new(width.saturating_sub(1), height.saturating_sub(1)),
+            background: RGB8::new(255, 255, 255),
+            output: Box::new(io::stdout()),
+        }
+    }
+
+    /// Specifies the color treated as the absence of dots.
+    pub fn background(&mut self, color: RGB8) -> &mut Self {
+        self.background = color;
+        self
+    }
+
+    /// Specifies where the canvas is printed when presented, the standard output by default.
+    pub fn output(&mut self, output: impl Write + 'static) -> &mut Self {
+        self.output = Box::new(output);
+        self
+    }
+
+    /// Returns the canvas point of a backend pixel, if it's within the canvas.
+    fn point(&self, (x, y): BackendCoord) -> Option<(u32, u32)> {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return None;
+        }
+
+        Some((x as u32, y as u32))
+    }
+}
+
+impl Display for BrailleBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for row in self.canvas.rows() {
+            writeln!(f, "{}", row.replace(' ', "\u{2800}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DrawingBackend for BrailleBackend {
+    type ErrorType = io::Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn ensure_prepared(&mut self) -> std::result::Result<(), DrawingErrorKind<io::Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> std::result::Result<(), DrawingErrorKind<io::Error>> {
+        let text = self.to_string();
+        self.output
+            .write_all(text.as_bytes())
+            .and_then(|_| self.output.flush())
+            .map_err(DrawingErrorKind::DrawingError)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> std::result::Result<(), DrawingErrorKind<io::Error>> {
+        let Some((x, y)) = self.point(point) else {
+            return Ok(());
+        };
+        if color.alpha < 0.5 {
+            return Ok(());
+        }
+
+        let (r, g, b) = color.rgb;
+        match RGB8::new(r, g, b) {
+            background if background == self.background => self.canvas.unset(x, y),
+            RGB8 { r: 0, g: 0, b: 0 } => self.canvas.set(x, y),
+            color => self.canvas.set_colored(x, y, rgb_to_pixelcolor(&color)),
+        }
+
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> std::result::Result<(), DrawingErrorKind<io::Error>> {
+        // let the rasterizer handle lines that are thick, faint, or cross the edges
+        let color = style.color();
+        let (r, g, b) = color.rgb;
+        match (self.point(from), self.point(to)) {
+            (Some((x1, y1)), Some((x2, y2)))
+                if style.stroke_width() == 1
+                    && color.alpha >= 0.5
+                    && RGB8::new(r, g, b) != self.background =>
+            {
+                match color.rgb {
+                    (0, 0, 0) => self.canvas.line(x1, y1, x2, y2),
+                    _ => self.canvas.line_colored(
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        rgb_to_pixelcolor(&RGB8::new(r, g, b)),
+                    ),
+                }
+                Ok(())
+            }
+            _ => plotters_backend::rasterizer::draw_line(self, from, to, style),
+        }
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> std::result::Result<(), DrawingErrorKind<io::Error>> {
+        let (width, height) = self.estimate_text_size(text, style)?;
+        let anchor = style.anchor();
+        let x = match anchor.h_pos {
+            HPos::Left => pos.0,
+            HPos::Center => pos.0 - width as i32 / 2,
+            HPos::Right => pos.0 - width as i32,
+        };
+        let y = match anchor.v_pos {
+            VPos::Top => pos.1,
+            VPos::Center => pos.1 - height as i32 / 2,
+            VPos::Bottom => pos.1 - height as i32,
+        };
+
+        if let Some((x, y)) = self.point((x.max(0), y.max(0))) {
+            let room = self.width.saturating_sub(x + 1);
+            self.canvas.text(x, y, room, text);
+        }
+
+        Ok(())
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        _style: &TStyle,
+    ) -> std::result::Result<(u32, u32), DrawingErrorKind<io::Error>> {
+        Ok((text.chars().count() as u32 * 2, 4))
+    }
+}
@@ -0,0 +1,191 @@
+//! Colormaps turning values into colors, shared by everything that colors by value.
+//!
+//! Built-in maps are available as constants and by name, custom ones can be registered
+//! under a name so they can be picked the same way.
+//!
+//! ```rust
+//! use textplots::colormap::{self, Colormap};
+//! use rgb::RGB8;
+//!
+//! assert_eq!(Colormap::VIRIDIS.map(0.0), RGB8::new(0x44, 0x01, 0x54));
+//! assert_eq!(Colormap::VIRIDIS.map(1.0), RGB8::new(0xfd, 0xe7, 0x25));
+//!
+//! let traffic = Colormap::new(vec![
+//!     RGB8::new(0, 200, 0),
+//!     RGB8::new(230, 180, 0),
+//!     RGB8::new(220, 0, 0),
+//! ]);
+//! colormap::register("traffic", traffic.clone());
+//! assert_eq!(colormap::by_name("traffic"), Some(traffic));
+//! assert_eq!(colormap::by_name("magma"), Some(Colormap::MAGMA));
+//! ```
+
+use rgb::RGB8;
+use std::borrow::Cow;
+use std::sync::Mutex;
+
+/// Returns the color written as `0xRRGGBB`.
+const fn hex(color: u32) -> RGB8 {
+    RGB8 {
+        r: (color >> 16) as u8,
+        g: (color >> 8) as u8,
+        b: color as u8,
+    }
+}
+
+const VIRIDIS: [RGB8; 9] = [
+    hex(0x440154),
+    hex(0x472d7b),
+    hex(0x3b528b),
+    hex(0x2c728e),
+    hex(0x21918c),
+    hex(0x28ae80),
+    hex(0x5ec962),
+    hex(0xaddc30),
+    hex(0xfde725),
+];
+
+const MAGMA: [RGB8; 9] = [
+    hex(0x000004),
+    hex(0x1c1044),
+    hex(0x4f127b),
+    hex(0x812581),
+    hex(0xb5367a),
+    hex(0xe55064),
+    hex(0xfb8761),
+    hex(0xfec287),
+    hex(0xfcfdbf),
+];
+
+const TURBO: [RGB8; 10] = [
+    hex(0x30123b),
+    hex(0x4662d7),
+    hex(0x36aaf9),
+    hex(0x1ae4b6),
+    hex(0x72fe5e),
+    hex(0xc8ef34),
+    hex(0xfaba39),
+    hex(0xf66b19),
+    hex(0xca2a04),
+    hex(0x7a0403),
+];
+
+const COOLWARM: [RGB8; 5] = [
+    hex(0x3b4cc0),
+    hex(0x7b9ff9),
+    hex(0xdddddd),
+    hex(0xf49a7b),
+    hex(0xb40426),
+];
+
+const PURPLE_GREEN: [RGB8; 9] = [
+    hex(0x40004b),
+    hex(0x762a83),
+    hex(0x9970ab),
+    hex(0xc2a5cf),
+    hex(0xf7f7f7),
+    hex(0xa6dba0),
+    hex(0x5aae61),
+    hex(0x1b7837),
+    hex(0x00441b),
+];
+
+/// Maps registered by `register`, looked up before the built-in ones.
+static REGISTRY: Mutex<Vec<(String, Colormap)>> = Mutex::new(Vec::new());
+
+/// Colors spaced evenly over `0.0..=1.0`, with values in between blending the nearest two.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Colormap {
+    stops: Cow<'static, [RGB8]>,
+}
+
+impl Colormap {
+    /// Perceptually uniform map from dark purple through teal to yellow.
+    pub const VIRIDIS: Colormap = Colormap::from_static(&VIRIDIS);
+    /// Perceptually uniform map from black through purple and orange to pale yellow.
+    pub const MAGMA: Colormap = Colormap::from_static(&MAGMA);
+    /// Rainbow-like map from dark blue through green to dark red.
+    pub const TURBO: Colormap = Colormap::from_static(&TURBO);
+    /// Diverging map from blue through light gray to red.
+    pub const COOLWARM: Colormap = Colormap::from_static(&COOLWARM);
+    /// Diverging map from purple through white to green.
+    pub const PURPLE_GREEN: Colormap = Colormap::from_static(&PURPLE_GREEN);
+
+    /// Creates a map blending the given colors, the first one being shown for `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no colors.
+    pub fn new(stops: Vec<RGB8>) -> Self {
+        if stops.is_empty() {
+            panic!("a colormap needs at least one color");
+        }
+
+        Self {
+            stops: Cow::Owned(stops),
+        }
+    }
+
+    const fn from_static(stops: &'static [RGB8]) -> Self {
+        Self {
+            stops: Cow::Borrowed(stops),
+        }
+    }
+
+    /// Returns the color for `t` between `0.0` and `1.0`, values out of it being pinned to
+    /// its ends and `NaN` shown as the first color.
+    pub fn map(&self, t: f32) -> RGB8 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let last = self.stops.len() - 1;
+        let position = t * last as f32;
+        let k = (position.floor() as usize).min(last);
+        let (from, to) = (self.stops[k], self.stops[(k + 1).min(last)]);
+        let blend =
+            |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * (position - k as f32)).round() as u8;
+
+        RGB8::new(
+            blend(from.r, to.r),
+            blend(from.g, to.g),
+            blend(from.b, to.b),
+        )
+    }
+
+    /// Returns the color for the value within the range, see `map`.
+    pub fn map_value(&self, value: f32, min: f32, max: f32) -> RGB8 {
+        if max > min {
+            self.map((value - min) / (max - min))
+        } else {
+            self.map(0.5)
+        }
+    }
+
+    /// Returns the same map going the other way round.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.stops.iter().rev().copied().collect())
+    }
+}
+
+/// Makes the map available through `by_name`, replacing any map registered under the same name.
+pub fn register(name: &str, map: Colormap) {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    registry.retain(|(registered, _)| registered != name);
+    registry.push((name.to_string(), map));
+}
+
+/// Returns the map registered under the name, or the built-in one if there is no such map.
+/// Built-in maps are called `viridis`, `magma`, `turbo`, `coolwarm`, and `purple_green`.
+pub fn by_name(name: &str) -> Option<Colormap> {
+    let registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, map)) = registry.iter().find(|(registered, _)| registered == name) {
+        return Some(map.clone());
+    }
+
+    match name {
+        "viridis" => Some(Colormap::VIRIDIS),
+        "magma" => Some(Colormap::MAGMA),
+        "turbo" => Some(Colormap::TURBO),
+        "coolwarm" => Some(Colormap::COOLWARM),
+        "purple_green" => Some(Colormap::PURPLE_GREEN),
+        _ => None,
+    }
+}
@@ -0,0 +1,149 @@
+//! Several series sharing one x vector, the way data arrives from CSV files and databases.
+//!
+//! ```rust
+//! use textplots::dataset::Dataset;
+//! use textplots::{Chart, Plot};
+//!
+//! let hours: Vec<f32> = (0..24).map(|h| h as f32).collect();
+//! let mut dataset = Dataset::new(hours.clone());
+//! dataset
+//!     .column("cpu", hours.iter().map(|h| 50.0 + 40.0 * (h / 4.0).sin()).collect())
+//!     .column("memory", hours.iter().map(|h| 30.0 + h).collect());
+//!
+//! Chart::new(120, 60, 0.0, 23.0)
+//!     .plot_dataset(&dataset)
+//!     .display();
+//! ```
+
+#[cfg(feature = "polars")]
+use polars::prelude::{DataFrame, DataType, PolarsResult};
+use rgb::RGB8;
+
+/// Colors given to the columns in turn, from the tab10 palette.
+pub const PALETTE: [RGB8; 6] = [
+    RGB8::new(31, 119, 180),
+    RGB8::new(255, 127, 14),
+    RGB8::new(44, 160, 44),
+    RGB8::new(214, 39, 40),
+    RGB8::new(148, 103, 189),
+    RGB8::new(23, 190, 207),
+];
+
+/// A named y column, zipped with the shared x vector.
+pub struct Column {
+    name: String,
+    points: Vec<(f32, f32)>,
+    color: Option<RGB8>,
+}
+
+impl Column {
+    /// Returns the name of the column.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the points of the column. There are as many as there are values
+    /// in the shorter of the column and the x vector.
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+
+    /// Returns the color the column was given, if any.
+    pub fn color(&self) -> Option<RGB8> {
+        self.color
+    }
+}
+
+/// Named y columns sharing an x vector. Plotted with `Plot::plot_dataset`, which
+/// draws each column as lines and lists it in the legend.
+pub struct Dataset {
+    x: Vec<f32>,
+    columns: Vec<Column>,
+}
+
+impl Dataset {
+    /// Creates a new `Dataset` without columns.
+    pub fn new(x: Vec<f32>) -> Self {
+        Self {
+            x,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds a column, colored from [`PALETTE`] when plotted.
+    pub fn column(&mut self, name: &str, y: Vec<f32>) -> &mut Self {
+        self.push(name, y, None)
+    }
+
+    /// Adds a column drawn with the specified color.
+    pub fn color_column(&mut self, name: &str, y: Vec<f32>, color: RGB8) -> &mut Self {
+        self.push(name, y, Some(color))
+    }
+
+    /// Creates a new `Dataset` of the columns of a [polars](https://crates.io/crates/polars)
+    /// data frame, with `ys` named after the columns they come from. Available with
+    /// the `polars` feature.
+    ///
+    /// Columns of any numeric type are cast to floats, and nulls become NaN.
+    /// Fails if a column is missing or can't be cast.
+    ///
+    /// ```rust
+    /// use polars::prelude::*;
+    /// use textplots::dataset::Dataset;
+    /// use textplots::{Chart, Plot};
+    ///
+    /// let df = df!(
+    ///     "day" => [1i32, 2, 3, 4, 5],
+    ///     "visits" => [120i64, 135, 128, 160, 171],
+    ///     "signups" => [12.0f64, 15.0, 11.0, 19.0, 22.0],
+    /// )
+    /// .unwrap();
+    /// let dataset = Dataset::from_df(&df, "day", &["visits", "signups"]).unwrap();
+    ///
+    /// Chart::new(120, 60, 1.0, 5.0)
+    ///     .plot_dataset(&dataset)
+    ///     .display();
+    /// ```
+    #[cfg(feature = "polars")]
+    pub fn from_df(df: &DataFrame, x: &str, ys: &[&str]) -> PolarsResult<Self> {
+        let floats = |name: &str| -> PolarsResult<Vec<f32>> {
+            let column = df.column(name)?.cast(&DataType::Float32)?;
+            Ok(column
+                .f32()?
+                .into_iter()
+                .map(|value| value.unwrap_or(f32::NAN))
+                .collect())
+        };
+
+        let mut dataset = Self::new(floats(x)?);
+        for &name in ys {
+            dataset.column(name, floats(name)?);
+        }
+        Ok(dataset)
+    }
+
+    fn push(&mut self, name: &str, y: Vec<f32>, color: Option<RGB8>) -> &mut Self {
+        let points = self.x.iter().copied().zip(y).collect();
+        self.columns.push(Column {
+            name: name.to_string(),
+            points,
+            color,
+        });
+        self
+    }
+
+    /// Returns the shared x vector.
+    pub fn x(&self) -> &[f32] {
+        &self.x
+    }
+
+    /// Returns the columns in the order they were added.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns the column with the specified name.
+    pub fn get(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
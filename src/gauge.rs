@@ -0,0 +1,210 @@
+//! Semi-circular dials showing a single value within its range.
+//!
+//! ```rust
+//! use textplots::gauge::Gauge;
+//! use rgb::RGB8;
+//!
+//! Gauge::new(60, 0.0, 100.0)
+//!     .value(72.5)
+//!     .threshold(0.0, RGB8::new(0, 200, 0))
+//!     .threshold(60.0, RGB8::new(230, 180, 0))
+//!     .threshold(85.0, RGB8::new(220, 0, 0))
+//!     .target(80.0)
+//!     .display();
+//! ```
+
+use crate::rgb_to_pixelcolor;
+use crate::scale::Scale;
+use crate::LabelFormat;
+use drawille::Canvas as BrailleCanvas;
+use rgb::RGB8;
+use std::f32::consts::PI;
+use std::fmt::{Display, Formatter, Result};
+use unicode_width::UnicodeWidthStr;
+
+/// Dial drawn as a braille arc from the minimum on the left to the maximum on the right,
+/// filled up to the current value, with the value and the range printed underneath.
+pub struct Gauge {
+    /// Width of the dial in canvas points.
+    size: u32,
+    min: f32,
+    max: f32,
+    value: Option<f32>,
+    target: Option<f32>,
+    /// Values the zones of the dial start at, along with their colors, sorted by value.
+    thresholds: Vec<(f32, RGB8)>,
+    label_format: LabelFormat,
+}
+
+impl Gauge {
+    /// Creates a new `Gauge` with the given width in canvas points and the range of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is less than 16.
+    pub fn new(size: u32, min: f32, max: f32) -> Self {
+        if size < 16 {
+            panic!("size should be at least 16");
+        }
+
+        Self {
+            size,
+            min,
+            max,
+            value: None,
+            target: None,
+            thresholds: Vec::new(),
+            label_format: LabelFormat::Value,
+        }
+    }
+
+    /// Specifies the value shown by the dial, values out of the range are pinned to its ends.
+    pub fn value(&mut self, value: f32) -> &mut Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Marks a target value with a tick across the dial.
+    pub fn target(&mut self, target: f32) -> &mut Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Starts a zone of the given color at the value. The dial is filled with the color of
+    /// the zone the current value falls into, and the outline of every zone with its own color.
+    pub fn threshold(&mut self, from: f32, color: RGB8) -> &mut Self {
+        self.thresholds.push((from, color));
+        self.thresholds
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        self
+    }
+
+    /// Specifies the format of the value and the range labels.
+    pub fn label_format(&mut self, format: LabelFormat) -> &mut Self {
+        self.label_format = format;
+        self
+    }
+
+    /// Prints the gauge.
+    pub fn display(&mut self) {
+        println!("{}", self);
+    }
+
+    /// Returns the color of the zone the value falls into, if any.
+    fn zone(&self, value: f32) -> Option<RGB8> {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(from, _)| value >= *from)
+            .map(|(_, color)| *color)
+    }
+
+    /// Draws the dial onto a new canvas.
+    fn canvas(&self) -> BrailleCanvas {
+        let radius = (self.size / 2) as f32;
+        let inner = radius * 0.7;
+        let (cx, cy) = (radius, radius);
+        let mut canvas = BrailleCanvas::new(self.size + 1, self.size / 2 + 1);
+
+        // angles go from pi for the minimum to zero for the maximum
+        let scale = Scale::new(self.min..self.max, 0.0..PI);
+        let angle_of = |value: f32| PI - scale.linear(value);
+        let value_angle = self.value.map(angle_of);
+        let fill = self.value.and_then(|value| self.zone(value));
+
+        let mut set = |x: u32, y: u32, color: Option<RGB8>| match color {
+            Some(color) => canvas.set_colored(x, y, rgb_to_pixelcolor(&color)),
+            None => canvas.set(x, y),
+        };
+
+        for y in 0..=cy as u32 {
+            for x in 0..=self.size {
+                let (dx, dy) = (x as f32 - cx, cy - y as f32);
+                let r = dx.hypot(dy);
+                if r > radius || r < inner {
+                    continue;
+                }
+
+                let angle = dy.atan2(dx);
+                let rim = r > radius - 1.0 || r < inner + 1.0;
+                let filled = value_angle.is_some_and(|value_angle| angle >= value_angle);
+
+                if filled {
+                    set(x, y, fill);
+                } else if rim {
+                    let zone = self.zone(scale.inv_linear(PI - angle));
+                    set(x, y, zone);
+                }
+            }
+        }
+
+        // close the ends of the arc
+        for x in 0..=(radius - inner) as u32 {
+            set(x, cy as u32, self.zone(self.min));
+            set(self.size - x, cy as u32, self.zone(self.max));
+        }
+
+        // the needle points from the center to the value
+        if let Some(angle) = value_angle {
+            let x = cx + (inner - 2.0) * angle.cos();
+            let y = cy - (inner - 2.0) * angle.sin();
+            let steps = inner as u32;
+            for step in 0..=steps {
+                let t = step as f32 / steps as f32;
+                let px = cx + (x - cx) * t;
+                let py = cy + (y - cy) * t;
+                set(px.round() as u32, py.round() as u32, fill);
+            }
+        }
+
+        // the target tick crosses the band from the inside to the outside
+        if let Some(target) = self.target {
+            let angle = angle_of(target);
+            let mut r = inner - 2.0;
+            while r <= radius {
+                let x = cx + r * angle.cos();
+                let y = cy - r * angle.sin();
+                set(x.round() as u32, y.round().max(0.0) as u32, None);
+                r += 0.5;
+            }
+        }
+
+        canvas
+    }
+}
+
+impl Display for Gauge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let rows = self.canvas().rows();
+        let columns = rows.first().map_or(0, |row| crate::visible_width(row));
+
+        for row in &rows {
+            writeln!(f, "{}", row.replace(' ', "\u{2800}"))?;
+        }
+
+        // range labels under the ends of the arc, with the value in the middle
+        let range = (self.min, self.max);
+        let mut labels = self.label_format.format_ticks(&[self.min, self.max], range);
+        let max = labels.pop().unwrap_or_default();
+        let min = labels.pop().unwrap_or_default();
+        let value = match self.value {
+            Some(value) => self.label_format.format_value(value, range),
+            None => String::new(),
+        };
+
+        let middle = columns.saturating_sub(value.width()) / 2;
+        let left = middle.saturating_sub(min.width()).max(1);
+        let right = columns
+            .saturating_sub(min.width() + left + value.width() + max.width())
+            .max(1);
+        writeln!(
+            f,
+            "{}{}{}{}{}",
+            min,
+            " ".repeat(left),
+            value,
+            " ".repeat(right),
+            max
+        )
+    }
+}
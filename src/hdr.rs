@@ -0,0 +1,76 @@
+//! Latency distributions recorded with [hdrhistogram](https://crates.io/crates/hdrhistogram),
+//! available with the `hdrhistogram` feature.
+//!
+//! ```rust
+//! use hdrhistogram::Histogram;
+//! use textplots::{hdr, Chart, Plot, Shape};
+//!
+//! let mut latencies = Histogram::<u64>::new(3).unwrap();
+//! for i in 1..1000u64 {
+//!     latencies.record(i * i / 100 + 5).unwrap();
+//! }
+//! let curve = hdr::percentiles(&latencies);
+//!
+//! Chart::new(120, 60, 0.0, 100.0)
+//!     .lineplot(&Shape::Lines(&curve))
+//!     .display();
+//! ```
+
+use hdrhistogram::{Counter, Histogram};
+
+/// Number of steps the percentile curve is sampled at between 0 and 100.
+const PERCENTILE_STEPS: usize = 200;
+
+/// Returns the percentile curve of the histogram, as points of percentile (0 to 100)
+/// and the recorded value at that percentile, to be plotted with `Shape::Lines`.
+/// An empty histogram has no points.
+pub fn percentiles<T: Counter>(hist: &Histogram<T>) -> Vec<(f32, f32)> {
+    if hist.is_empty() {
+        return Vec::new();
+    }
+
+    (0..=PERCENTILE_STEPS)
+        .map(|i| {
+            let quantile = i as f64 / PERCENTILE_STEPS as f64;
+            (
+                (quantile * 100.0) as f32,
+                hist.value_at_quantile(quantile) as f32,
+            )
+        })
+        .collect()
+}
+
+/// Returns the counts of the histogram grouped into `bins` buckets of equal width between
+/// the smallest and the largest recorded value, to be plotted with `Shape::Bars`.
+/// Every point is the start of a bucket and its count, like `utils::histogram` gives.
+///
+/// ```rust
+/// use hdrhistogram::Histogram;
+/// use textplots::hdr;
+///
+/// let mut hist = Histogram::<u64>::new(3).unwrap();
+/// hist.record_n(10, 3).unwrap();
+/// hist.record(20).unwrap();
+/// assert_eq!(vec![(10.0, 3.0), (15.0, 1.0)], hdr::bars(&hist, 2));
+/// ```
+pub fn bars<T: Counter>(hist: &Histogram<T>, bins: usize) -> Vec<(f32, f32)> {
+    if hist.is_empty() || bins == 0 {
+        return Vec::new();
+    }
+
+    let min = hist.min() as f64;
+    let max = hist.max() as f64;
+    let step = ((max - min) / bins as f64).max(f64::MIN_POSITIVE);
+    let mut output = vec![0; bins];
+
+    for value in hist.iter_recorded() {
+        let bucket = ((value.value_iterated_to() as f64 - min) / step) as usize;
+        output[bucket.min(bins - 1)] += value.count_at_value().as_u64();
+    }
+
+    output
+        .into_iter()
+        .enumerate()
+        .map(|(k, count)| ((min + k as f64 * step) as f32, count as f32))
+        .collect()
+}
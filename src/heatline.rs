@@ -0,0 +1,84 @@
+//! Lines colored segment by segment by a value other than y, like the speed along a track.
+//!
+//! ```rust
+//! use textplots::colormap::Colormap;
+//! use textplots::heatline::Heatline;
+//! use textplots::{Chart, Plot, TextBuilder};
+//!
+//! let track: Vec<(f32, f32)> = (0..100).map(|i| (i as f32 / 10.0, (i as f32 / 10.0).sin())).collect();
+//! let speed: Vec<f32> = (0..100).map(|i| (i as f32 / 10.0).cos().abs()).collect();
+//! let heatline = Heatline::new(&track, &speed, Colormap::TURBO);
+//!
+//! Chart::new(120, 60, 0.0, 10.0)
+//!     .customplot(&heatline)
+//!     .colorbar(Colormap::TURBO, 0.0, 1.0)
+//!     .display();
+//! ```
+
+use crate::colormap::Colormap;
+use crate::{DrawContext, Drawable};
+
+/// Points connected with lines, each segment colored by the mean of the values
+/// at its ends. Plotted with `Plot::customplot`.
+pub struct Heatline<'a> {
+    points: &'a [(f32, f32)],
+    values: &'a [f32],
+    map: Colormap,
+    /// Values mapped to the ends of the colormap, if not the smallest and largest ones.
+    range: Option<(f32, f32)>,
+}
+
+impl<'a> Heatline<'a> {
+    /// Creates a new `Heatline` of the points colored by the values at the same index.
+    /// Points without a value are left out.
+    pub fn new(points: &'a [(f32, f32)], values: &'a [f32], map: Colormap) -> Self {
+        Self {
+            points,
+            values,
+            map,
+            range: None,
+        }
+    }
+
+    /// Specifies the values mapped to the ends of the colormap, instead of the smallest
+    /// and largest ones, so that several lines can share a scale.
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Returns the values mapped to the ends of the colormap.
+    fn value_range(&self) -> (f32, f32) {
+        self.range.unwrap_or_else(|| {
+            let finite = self.values.iter().copied().filter(|v| v.is_finite());
+            let min = finite.clone().fold(f32::INFINITY, f32::min);
+            let max = finite.fold(f32::NEG_INFINITY, f32::max);
+            (min, max)
+        })
+    }
+}
+
+impl Drawable for Heatline<'_> {
+    fn draw(&self, ctx: &mut DrawContext) {
+        let (min, max) = self.value_range();
+        let segments = self.points.windows(2).zip(self.values.windows(2));
+
+        for (points, values) in segments {
+            let value = (values[0] + values[1]) / 2.0;
+            ctx.set_color(Some(self.map.map_value(value, min, max)));
+            ctx.line(points[0], points[1]);
+        }
+    }
+
+    fn y_bounds(&self) -> Option<(f32, f32)> {
+        let ys = self
+            .points
+            .iter()
+            .take(self.values.len())
+            .map(|(_, y)| *y)
+            .filter(|y| y.is_finite());
+        let min = ys.clone().fold(f32::INFINITY, f32::min);
+        let max = ys.fold(f32::NEG_INFINITY, f32::max);
+        (min <= max).then_some((min, max))
+    }
+}
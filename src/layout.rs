@@ -0,0 +1,139 @@
+//! Charts laid out together, like prices over the traded volumes.
+//!
+//! ```rust
+//! use textplots::layout::dual_pane;
+//! use textplots::{Chart, Plot, Shape, ShapeStyleBuilder};
+//!
+//! let prices = [(1.0, 101.2), (2.0, 103.5), (3.0, 102.8), (4.0, 106.1), (5.0, 105.4)];
+//! let volumes = [(1.0, 1200.0), (2.0, 3400.0), (3.0, 2100.0), (4.0, 5200.0), (5.0, 2600.0)];
+//!
+//! let (price_line, volume_bars) = (Shape::Lines(&prices), Shape::Bars(&volumes));
+//!
+//! let mut price = Chart::new(120, 40, 1.0, 5.0);
+//! let price = price.lineplot(&price_line);
+//! let mut volume = Chart::new(120, 16, 1.0, 5.0);
+//! let volume = volume.lineplot(&volume_bars).baseline(0.0);
+//!
+//! println!("{}", dual_pane(price, volume));
+//! ```
+
+use crate::{Chart, LabelFormat};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Renders `top` over `bottom` sharing the x-axis: `bottom` is drawn with the x range and the
+/// canvas width of `top`, the gutters left of the canvases are widened to the wider one so that
+/// the canvases line up, and only `bottom` is labeled along x. Both charts are left as they
+/// were, so `bottom` gets its own range and width back afterwards.
+pub fn dual_pane(top: &mut Chart, bottom: &mut Chart) -> String {
+    let (x_range, width) = (bottom.x_range(), bottom.width);
+    let (xmin, xmax) = top.x_range();
+    if x_range != (xmin, xmax) {
+        bottom.set_x_range(xmin, xmax);
+    }
+    if width != top.width {
+        bottom.resize(top.width, bottom.height);
+    }
+
+    let gutter = |chart: &Chart| chart.y_label_sides(chart.canvas.rows().len()).2;
+    if let Some(width) = gutter(top).max(gutter(bottom)) {
+        top.min_gutter = width.max(1);
+        bottom.min_gutter = width.max(1);
+    }
+
+    top.hide_x_labels = true;
+    let mut out = top.render();
+    out.push_str(&bottom.render());
+
+    top.hide_x_labels = false;
+    top.min_gutter = 0;
+    bottom.min_gutter = 0;
+    if bottom.width != width {
+        bottom.resize(width, bottom.height);
+    }
+    if bottom.x_range() != x_range {
+        bottom.set_x_range(x_range.0, x_range.1);
+    }
+    out
+}
+
+/// X-axis shared by a column of charts, which take its range whenever they are rendered,
+/// so that panning or zooming it moves them all, and its label format if it has one.
+/// Clones refer to the same axis.
+///
+/// ```rust
+/// use textplots::layout::SharedXAxis;
+/// use textplots::{Chart, LabelFormat, Plot, Shape};
+///
+/// let axis = SharedXAxis::new(0.0, 60.0);
+/// axis.set_label_format(LabelFormat::Custom(Box::new(|x| format!("{:.0}s", x))));
+///
+/// let cpu = Shape::Continuous(Box::new(|t| 50.0 + 30.0 * (t / 7.0).sin()));
+/// let memory = Shape::Continuous(Box::new(|t| 40.0 + t / 3.0));
+/// let mut top = Chart::new(120, 30, 0.0, 60.0);
+/// let top = top.lineplot(&cpu).share_x_axis(&axis);
+/// let mut bottom = Chart::new(120, 30, 0.0, 60.0);
+/// let bottom = bottom.lineplot(&memory).share_x_axis(&axis);
+///
+/// // zoom into the last half minute on both charts
+/// axis.set_range(30.0, 60.0);
+/// top.display();
+/// bottom.display();
+///
+/// assert_eq!((30.0, 60.0), bottom.x_range());
+/// ```
+#[derive(Clone)]
+pub struct SharedXAxis {
+    state: Rc<RefCell<AxisState>>,
+}
+
+struct AxisState {
+    range: (f32, f32),
+    label_format: Option<Rc<LabelFormat>>,
+}
+
+impl SharedXAxis {
+    /// Creates a new `SharedXAxis` with the given range, leaving the label format to the charts.
+    pub fn new(xmin: f32, xmax: f32) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(AxisState {
+                range: (xmin, xmax),
+                label_format: None,
+            })),
+        }
+    }
+
+    /// Returns the range as `(xmin, xmax)`.
+    pub fn range(&self) -> (f32, f32) {
+        self.state.borrow().range
+    }
+
+    /// Changes the range of every chart following the axis.
+    pub fn set_range(&self, xmin: f32, xmax: f32) {
+        self.state.borrow_mut().range = (xmin, xmax);
+    }
+
+    /// Moves the range by `delta`, keeping its width.
+    pub fn pan(&self, delta: f32) {
+        let (xmin, xmax) = self.range();
+        self.set_range(xmin + delta, xmax + delta);
+    }
+
+    /// Scales the width of the range by `factor` around its middle, zooming in with
+    /// factors below 1 and out with factors above.
+    pub fn zoom(&self, factor: f32) {
+        let (xmin, xmax) = self.range();
+        let (middle, half) = ((xmin + xmax) / 2.0, (xmax - xmin) / 2.0 * factor);
+        self.set_range(middle - half, middle + half);
+    }
+
+    /// Specifies the format of the x-axis labels of every chart following the axis.
+    pub fn set_label_format(&self, format: LabelFormat) {
+        self.state.borrow_mut().label_format = Some(Rc::new(format));
+    }
+
+    /// Returns the label format, if one was set.
+    pub(crate) fn label_format(&self) -> Option<Rc<LabelFormat>> {
+        self.state.borrow().label_format.clone()
+    }
+}
@@ -49,17 +49,46 @@
 //!
 //! <img src="https://github.com/loony-bean/textplots-rs/blob/master/doc/demo3.png?raw=true"/>
 
+pub mod animation;
+#[cfg(feature = "plotters")]
+pub mod backend;
+pub mod colormap;
+pub mod dataset;
+pub mod gauge;
+#[cfg(feature = "hdrhistogram")]
+pub mod hdr;
+pub mod heatline;
+pub mod layout;
+pub mod matrix;
+pub mod owned;
+pub mod pie;
+pub mod radar;
+#[cfg(feature = "gif")]
+mod raster;
+#[cfg(all(feature = "resize", unix))]
+pub mod resize;
 pub mod scale;
+pub mod spec;
 pub mod utils;
+pub mod value;
 
+use colored::Colorize;
+use colormap::Colormap;
+use dataset::Dataset;
 use drawille::Canvas as BrailleCanvas;
 use drawille::PixelColor;
+use layout::SharedXAxis;
 use rgb::RGB8;
 use scale::Scale;
 use std::cmp;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::default::Default;
 use std::f32;
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{Display, Formatter, Result, Write};
+use std::iter;
+use std::mem;
+use std::ops::{Deref, Range, RangeInclusive};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// How the chart will do the ranging on axes
 #[derive(PartialEq)]
@@ -70,12 +99,92 @@ enum ChartRangeMethod {
     FixedRange,
 }
 
+/// Which y-axis a series is plotted against.
+#[derive(Clone, Copy, PartialEq)]
+enum YAxis {
+    /// Left-hand (default) axis, labeled on the side set by `LabelPosition`.
+    Primary,
+    /// Secondary axis, labeled on the opposite side.
+    Secondary,
+}
+
+/// Region of the chart shaded behind the shapes.
+enum Span {
+    /// Band between two y values, across the whole chart.
+    Horizontal(Range<f32>),
+    /// Band between two x values, from the bottom to the top of the chart.
+    Vertical(Range<f32>),
+}
+
+/// Plotted data, either a shape borrowed from the caller or data the caller lends
+/// without a shape, like the columns of a dataset.
+enum SeriesShape<'a> {
+    /// Shape plotted as it is.
+    Borrowed(&'a Shape<'a>),
+    /// Points plotted as `Shape::Lines`.
+    Lines(&'a [(f32, f32)]),
+}
+
+impl<'a> SeriesShape<'a> {
+    /// Returns the shape, built on the spot for data lent without one.
+    fn get(&self) -> ShapeRef<'_, 'a> {
+        match *self {
+            SeriesShape::Borrowed(shape) => ShapeRef::Borrowed(shape),
+            SeriesShape::Lines(points) => ShapeRef::Built(Shape::Lines(points)),
+        }
+    }
+}
+
+/// Shape of a series, see `SeriesShape::get`.
+enum ShapeRef<'s, 'a> {
+    Borrowed(&'s Shape<'a>),
+    Built(Shape<'a>),
+}
+
+impl<'a> Deref for ShapeRef<'_, 'a> {
+    type Target = Shape<'a>;
+
+    fn deref(&self) -> &Shape<'a> {
+        match self {
+            ShapeRef::Borrowed(shape) => shape,
+            ShapeRef::Built(shape) => shape,
+        }
+    }
+}
+
+/// Shape to be presented on the canvas along with its display options.
+struct Series<'a> {
+    /// The plotted data.
+    shape: SeriesShape<'a>,
+    /// Color of the series, if any.
+    color: Option<RGB8>,
+    /// Axis the series is scaled against.
+    axis: YAxis,
+    /// Function values at every canvas column for continuous shapes, evaluated once
+    /// when the series is added, as the function might be expensive or non-deterministic.
+    samples: Vec<f32>,
+    /// Smallest and largest values of continuous shapes within every canvas column, evaluated
+    /// along with the samples when the chart takes more than one sample per column.
+    envelope: Vec<(f32, f32)>,
+    /// Position in the drawing order, series with higher values are drawn over the others.
+    z: i32,
+    /// Whether overlapping points are drawn denser and brighter instead of once.
+    density: bool,
+}
+
+/// Smallest canvas width in points, smaller widths are raised to it.
+pub const MIN_WIDTH: u32 = 32;
+/// Smallest canvas height in points, smaller heights are raised to it.
+pub const MIN_HEIGHT: u32 = 3;
+
 /// Controls the drawing.
 pub struct Chart<'a> {
     /// Canvas width in points.
     width: u32,
     /// Canvas height in points.
     height: u32,
+    /// Whether the requested size was raised to the smallest one supported.
+    clamped: bool,
     /// X-axis start value.
     xmin: f32,
     /// X-axis end value.
@@ -86,46 +195,580 @@ pub struct Chart<'a> {
     ymax: f32,
     /// The type of y axis ranging we'll do
     y_ranging: ChartRangeMethod,
+    /// Smallest and largest y values of the shapes plotted against the y-axis.
+    data_y_range: (f32, f32),
+    /// Smallest and largest y values of the shapes plotted against the secondary y-axis.
+    data_y2_range: (f32, f32),
+    /// Fraction of the data range added above and below automatically ranged y-axes.
+    y_padding: f32,
+    /// Secondary y-axis start value (potentially calculated automatically).
+    y2min: f32,
+    /// Secondary y-axis end value (potentially calculated automatically).
+    y2max: f32,
+    /// The type of secondary y axis ranging we'll do
+    y2_ranging: ChartRangeMethod,
     /// Collection of shapes to be presented on the canvas.
-    shapes: Vec<(&'a Shape<'a>, Option<RGB8>)>,
+    shapes: Vec<Series<'a>>,
     /// Underlying canvas object.
     canvas: BrailleCanvas,
+    /// Border style, if the chart is displayed with a border.
+    border_style: Option<BorderStyle>,
     /// X-axis style.
     x_style: LineStyle,
     /// Y-axis style.
     y_style: LineStyle,
+    /// Position of the axes.
+    axis_position: AxisPosition,
+    /// Color of the axis lines.
+    axis_color: Option<RGB8>,
+    /// Color of the axis labels.
+    label_color: Option<RGB8>,
+    /// Whether the x value at the middle of the canvas is labeled between xmin and xmax.
+    x_label_middle: bool,
+    /// Whether the last value of every series is printed right of the canvas.
+    end_values: bool,
+    /// Whether the x-axis labels are left out, for a chart stacked over another one.
+    hide_x_labels: bool,
+    /// Smallest width of the gutter left of the canvas, for lining up stacked charts.
+    min_gutter: usize,
+    /// X-axis shared with other charts, which the x range follows when rendering.
+    shared_x: Option<SharedXAxis>,
     /// X-axis label format.
     x_label_format: LabelFormat,
     /// Y-axis label format.
     y_label_format: LabelFormat,
+    /// Secondary y-axis label format.
+    y2_label_format: LabelFormat,
+    /// Y-axis label position.
+    y_label_position: LabelPosition,
     /// Y-axis tick label density
     y_tick_display: TickDisplay,
+    /// Custom x-axis tick positions with optional labels, overriding the automatic ones.
+    x_ticks: Vec<(f32, Option<String>)>,
+    /// Custom y-axis tick positions with optional labels, overriding the automatic ones.
+    y_ticks: Vec<(f32, Option<String>)>,
+    /// Whether custom x-axis tick labels are abbreviated to fit before the next tick.
+    abbreviate_x_ticks: bool,
+    /// Number of unlabeled notches between every two labeled ticks of each axis.
+    minor_ticks: u32,
+    /// Text displayed above the chart.
+    caption: Option<String>,
+    /// Text displayed under the chart.
+    footer: Option<String>,
+    /// Title of the x-axis, displayed under its labels.
+    x_title: Option<String>,
+    /// Title of the y-axis, displayed above its labels.
+    y_title: Option<String>,
+    /// Whether the names of the series are listed under the chart.
+    legend: bool,
+    /// Whether summary statistics of every series are displayed under the chart.
+    stats: bool,
+    /// Colormap shown as a bar to the right of the chart, along with the values at its ends.
+    colorbar: Option<(Colormap, f32, f32)>,
+    /// Where the horizontal segments of steps are placed.
+    step_style: StepStyle,
+    /// Color of cells where colored series overlap.
+    color_mixing: ColorMixing,
+    /// How lines connect consecutive points.
+    interpolation: Interpolation,
+    /// Whether polygons are filled rather than outlined.
+    polygon_fill: bool,
+    /// Whether the gaps of series with missing values are shaded.
+    shade_gaps: bool,
+    /// Y value bars are drawn from, or `None` to draw them from the bottom of the canvas.
+    baseline: Option<f32>,
+    /// Whether every series is rescaled to its own range for display.
+    normalized: bool,
+    /// Fraction of the canvas height a continuous shape has to jump between neighbouring
+    /// columns to be checked for a discontinuity.
+    jump_threshold: f32,
+    /// Number of samples continuous shapes take within every canvas column.
+    samples_per_column: u32,
+    /// Whether points and lines are drawn as the range of their values in every canvas column.
+    envelope: bool,
+    /// Whether dense point series are reduced to one column's worth of points before drawing.
+    downsample: bool,
+    /// Called with the diagnostics when rendering, if some points can't be drawn.
+    strict: Option<Report>,
+    /// Regions shaded behind the shapes, along with their color.
+    spans: Vec<(Span, Option<RGB8>)>,
+    /// User-defined geometry drawn on top of the shapes, along with its color.
+    drawables: Vec<(&'a dyn Drawable, Option<RGB8>)>,
+    /// Character printed for empty canvas cells.
+    blank_char: char,
+    /// Whether arrowheads are drawn at the positive ends of the axes.
+    axis_arrows: bool,
+    /// Whether a ring is drawn where the axes cross.
+    origin_marker: bool,
+    /// Range of values cut out of the primary y-axis.
+    y_break: Option<Range<f32>>,
+    /// Descriptions of the plotted shapes, in the order they were plotted.
+    series: Vec<SeriesInfo>,
+}
+
+/// Describes a plotted series, for rendering legends or selection lists outside of the chart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriesInfo {
+    /// Name given to the series, if any.
+    pub name: Option<String>,
+    /// Color of the series, if any.
+    pub color: Option<RGB8>,
+    /// Number of data points, or samples for continuous shapes.
+    pub points: usize,
+}
+
+/// Counts of the plotted points that can't be drawn as they are, see `Chart::diagnostics`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// Points outside of the x range or the range of their y-axis, which are left out or clipped.
+    pub out_of_range: usize,
+    /// Points with a NaN coordinate, which are left out.
+    pub nan: usize,
+    /// Points with an infinite coordinate, and infinite samples of continuous shapes, which are
+    /// left out.
+    pub non_normal: usize,
+    /// Whether the chart has nothing to draw in, see `Chart::is_degenerate`.
+    pub degenerate: bool,
+}
+
+impl Diagnostics {
+    /// Returns whether every point can be drawn as it is.
+    pub fn is_clean(&self) -> bool {
+        *self == Diagnostics::default()
+    }
+}
+
+impl Display for Diagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{} points out of range, {} NaN, {} non-normal",
+            self.out_of_range, self.nan, self.non_normal
+        )?;
+        if self.degenerate {
+            write!(f, ", degenerate range")?;
+        }
+        Ok(())
+    }
 }
 
+/// Callback given the diagnostics of a chart that can't be drawn as it is, see `Chart::strict`.
+type Report = Box<dyn FnMut(&Diagnostics)>;
+
 /// Specifies different kinds of plotted data.
 pub enum Shape<'a> {
-    /// Real value function.
+    /// Real value function. Samples that aren't finite are left out, zeros are drawn.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let zero = Shape::Continuous(Box::new(|_| 0.0));
+    ///
+    /// let mut chart = Chart::new(60, 12, 0.0, 1.0);
+    /// let chart = chart.lineplot(&zero);
+    ///
+    /// assert!(!chart.is_degenerate());
+    /// assert_eq!((-0.5, 0.5), chart.y_range());
+    /// // the line runs through the middle of the canvas
+    /// assert!(chart.render().lines().nth(1).unwrap().starts_with("⠤⠤⠤⠤"));
+    /// ```
     Continuous(Box<dyn Fn(f32) -> f32 + 'a>),
+    /// Real value function that can be called from multiple threads at once.
+    /// With the `rayon` feature enabled it is sampled in parallel, which pays off for expensive functions.
+    ParallelContinuous(Box<dyn Fn(f32) -> f32 + Sync + 'a>),
     /// Points of a scatter plot.
     Points(&'a [(f32, f32)]),
+    /// Points of a scatter plot, each drawn in its own color regardless of the series color.
+    ///
+    /// ```rust
+    /// use rgb::RGB8;
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let red = RGB8::new(255, 0, 0);
+    /// let blue = RGB8::new(0, 0, 255);
+    /// let clusters = [(1.0, 1.0, red), (1.5, 1.2, red), (4.0, 3.0, blue), (4.2, 3.5, blue)];
+    ///
+    /// Chart::new(120, 60, 0.0, 5.0)
+    ///     .lineplot(&Shape::ColoredPoints(&clusters))
+    ///     .display();
+    /// ```
+    ColoredPoints(&'a [(f32, f32, RGB8)]),
+    /// Points of a scatter plot drawn as discs, the third value setting their size.
+    /// The largest value is drawn 7 dots across, and the rest in proportion down to a single dot.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// // population of cities by latitude and longitude
+    /// let cities = [(2.35, 48.86, 2.1), (13.40, 52.52, 3.6), (-3.70, 40.42, 3.3), (12.50, 41.90, 2.8)];
+    ///
+    /// Chart::new(120, 60, -10.0, 20.0)
+    ///     .lineplot(&Shape::Bubbles(&cities))
+    ///     .display();
+    /// ```
+    Bubbles(&'a [(f32, f32, f32)]),
+    /// Points of a scatter plot, each labeled with a short text written in the cells next to it.
+    /// Labels are placed where they don't overlap other labels, preferring empty cells on the
+    /// right, then on the left, above and below the point, and left out if there's no room.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let cities = [(2.35, 48.86, "Paris"), (13.40, 52.52, "Berlin"), (-3.70, 40.42, "Madrid")];
+    ///
+    /// let mut chart = Chart::new(120, 60, -10.0, 20.0);
+    /// let frame = chart.lineplot(&Shape::LabeledPoints(&cities)).render();
+    ///
+    /// assert!(frame.contains("Berlin"));
+    /// ```
+    LabeledPoints(&'a [(f32, f32, &'a str)]),
     /// Points connected with lines.
     Lines(&'a [(f32, f32)]),
     /// Points connected in step fashion.
     Steps(&'a [(f32, f32)]),
     /// Points represented with bars.
     Bars(&'a [(f32, f32)]),
+    /// Points connected with lines, with missing values breaking the line instead of being
+    /// dropped. The gaps can be shaded, see `ShapeStyleBuilder::shade_gaps`.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, ShapeStyleBuilder};
+    ///
+    /// // the sensor was offline for a couple of readings
+    /// let readings = [
+    ///     (0.0, Some(1.0)),
+    ///     (1.0, Some(1.5)),
+    ///     (2.0, None),
+    ///     (3.0, None),
+    ///     (4.0, Some(2.0)),
+    ///     (5.0, Some(1.8)),
+    /// ];
+    ///
+    /// Chart::new(120, 60, 0.0, 5.0)
+    ///     .lineplot(&Shape::Gapped(&readings))
+    ///     .shade_gaps(true)
+    ///     .display();
+    /// ```
+    Gapped(&'a [(f32, Option<f32>)]),
+    /// Bézier curve of the control points, quadratic for three points and cubic for four.
+    /// The curve starts at the first point and ends at the last one, passing near the others.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// // the ease-in-out timing curve
+    /// let ease = [(0.0, 0.0), (0.42, 0.0), (0.58, 1.0), (1.0, 1.0)];
+    ///
+    /// Chart::new(120, 60, 0.0, 1.0)
+    ///     .lineplot(&Shape::Bezier(&ease))
+    ///     .display();
+    /// ```
+    Bezier(&'a [(f32, f32)]),
+    /// Closed polygon with the points as its corners, drawn as an outline or filled,
+    /// see `ShapeStyleBuilder::polygon_fill`.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, ShapeStyleBuilder};
+    ///
+    /// // feasible region of x + y <= 4, x <= 3, y <= 3
+    /// let region = [(0.0, 0.0), (3.0, 0.0), (3.0, 1.0), (1.0, 3.0), (0.0, 3.0)];
+    ///
+    /// Chart::new(120, 60, 0.0, 4.0)
+    ///     .lineplot(&Shape::Polygon(&region))
+    ///     .polygon_fill(true)
+    ///     .display();
+    /// ```
+    Polygon(&'a [(f32, f32)]),
+    /// Circle outline in data coordinates, which shows as an ellipse unless both axes
+    /// have the same scale.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let tolerance = Shape::Circle { center: (1.0, 1.0), radius: 0.5 };
+    /// let orbit = Shape::Ellipse { center: (0.0, 0.0), radii: (2.0, 1.5) };
+    ///
+    /// Chart::new_with_y_range(120, 60, -3.0, 3.0, -2.0, 2.0)
+    ///     .lineplot(&tolerance)
+    ///     .lineplot(&orbit)
+    ///     .display();
+    /// ```
+    Circle {
+        /// Center of the circle.
+        center: (f32, f32),
+        /// Radius of the circle.
+        radius: f32,
+    },
+    /// Outline of an ellipse with axes along the chart axes, in data coordinates.
+    Ellipse {
+        /// Center of the ellipse.
+        center: (f32, f32),
+        /// Radii of the ellipse along the x-axis and the y-axis.
+        radii: (f32, f32),
+    },
+}
+
+impl<'a> Shape<'a> {
+    /// Returns the entries of a map, like the counters of a metrics histogram, as points
+    /// sorted by x, to be plotted as any shape of points. Works with both `BTreeMap`
+    /// and `HashMap`.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let mut latencies: BTreeMap<u16, u32> = BTreeMap::new();
+    /// for ms in [12, 15, 15, 20, 20, 20, 35] {
+    ///     *latencies.entry(ms).or_default() += 1;
+    /// }
+    /// let points = Shape::from_map(&latencies);
+    ///
+    /// Chart::new(120, 60, 0.0, 40.0)
+    ///     .lineplot(&Shape::Bars(&points))
+    ///     .display();
+    /// ```
+    pub fn from_map<'m, K, V, M>(map: M) -> Vec<(f32, f32)>
+    where
+        M: IntoIterator<Item = (&'m K, &'m V)>,
+        K: Into<f64> + Copy + 'm,
+        V: Into<f64> + Copy + 'm,
+    {
+        let mut points: Vec<(f32, f32)> = map
+            .into_iter()
+            .map(|(&k, &v)| (k.into() as f32, v.into() as f32))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points
+    }
+
+    /// Creates a continuous shape of the least squares line fitted to the points,
+    /// handy for overlaying a trend on a scatter plot.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let points = [(0.0, 1.0), (1.0, 2.5), (2.0, 2.0), (3.0, 4.0)];
+    ///
+    /// Chart::new(120, 60, 0.0, 3.0)
+    ///     .lineplot(&Shape::Points(&points))
+    ///     .lineplot(&Shape::trend_line(&points))
+    ///     .display();
+    /// ```
+    pub fn trend_line(data: &[(f32, f32)]) -> Shape<'a> {
+        let (slope, intercept) = utils::linear_fit(data);
+        Shape::Continuous(Box::new(move |x| slope * x + intercept))
+    }
+
+    /// Creates a continuous shape of the derivative of a continuous shape, computed with
+    /// central differences.
+    ///
+    /// Panics if the shape is not continuous.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let sin = Shape::Continuous(Box::new(|x| x.sin()));
+    /// let cos = Shape::derivative_of(&sin);
+    ///
+    /// Chart::new(120, 60, -5.0, 5.0)
+    ///     .lineplot(&sin)
+    ///     .lineplot(&cos)
+    ///     .display();
+    /// ```
+    pub fn derivative_of(shape: &'a Shape) -> Shape<'a> {
+        let f = shape
+            .function()
+            .expect("derivative_of expects a continuous shape");
+
+        Shape::Continuous(Box::new(move |x| {
+            // the step balancing rounding and truncation errors of central differences
+            let h = f32::EPSILON.cbrt() * x.abs().max(1.0);
+            (f(x + h) - f(x - h)) / (2.0 * h)
+        }))
+    }
+
+    /// Creates a continuous shape of the integral of a continuous shape from `origin` to x,
+    /// computed with Simpson's rule. The integral is zero at the origin, and negative to
+    /// the left of it for positive functions.
+    ///
+    /// Panics if the shape is not continuous.
+    ///
+    /// ```rust
+    /// use textplots::Shape;
+    ///
+    /// let line = Shape::Continuous(Box::new(|x| 2.0 * x));
+    /// let Shape::Continuous(square) = Shape::integral_of(&line, 0.0) else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// assert!((square(3.0) - 9.0).abs() < 1e-4);
+    /// ```
+    pub fn integral_of(shape: &'a Shape, origin: f32) -> Shape<'a> {
+        const INTERVALS: usize = 64;
+
+        let f = shape
+            .function()
+            .expect("integral_of expects a continuous shape");
+
+        Shape::Continuous(Box::new(move |x| {
+            let h = (x - origin) as f64 / INTERVALS as f64;
+            let sum: f64 = (0..=INTERVALS)
+                .map(|i| {
+                    let weight = match i {
+                        0 | INTERVALS => 1.0,
+                        i if i % 2 == 1 => 4.0,
+                        _ => 2.0,
+                    };
+                    weight * f((origin as f64 + i as f64 * h) as f32) as f64
+                })
+                .sum();
+            (sum * h / 3.0) as f32
+        }))
+    }
+
+    /// Returns the center and radii of a circle or an ellipse.
+    fn ellipse(&self) -> Option<((f32, f32), (f32, f32))> {
+        match *self {
+            Shape::Circle { center, radius } => Some((center, (radius, radius))),
+            Shape::Ellipse { center, radii } => Some((center, radii)),
+            _ => None,
+        }
+    }
+
+    /// Returns the function of a continuous shape.
+    fn function(&self) -> Option<&dyn Fn(f32) -> f32> {
+        match self {
+            Shape::Continuous(f) => Some(f),
+            Shape::ParallelContinuous(f) => Some(f),
+            _ => None,
+        }
+    }
 }
 
 /// Provides an interface for drawing plots.
 pub trait Plot<'a> {
     /// Draws a [line chart](https://en.wikipedia.org/wiki/Line_chart) of points connected by straight line segments.
-    fn lineplot(&'a mut self, shape: &'a Shape) -> &'a mut Chart;
+    fn lineplot(&'a mut self, shape: &'a Shape) -> &'a mut Chart<'a>;
+
+    /// Same as `lineplot`, but the shape is scaled against the secondary y-axis,
+    /// which is labeled on the opposite side of the canvas.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+    ///
+    /// let requests = Shape::Continuous(Box::new(|x| 1000.0 + 500.0 * x.sin()));
+    /// let latency = Shape::Continuous(Box::new(|x| 20.0 + 5.0 * x.cos()));
+    ///
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .lineplot(&requests)
+    ///     .lineplot_secondary(&latency)
+    ///     .y2_label_format(LabelFormat::Custom(Box::new(|y| format!("{:.0}ms", y))))
+    ///     .display();
+    /// ```
+    fn lineplot_secondary(&'a mut self, shape: &'a Shape) -> &'a mut Chart<'a>;
+
+    /// Draws user-defined geometry, see [`Drawable`].
+    fn customplot(&'a mut self, drawable: &'a dyn Drawable) -> &'a mut Chart<'a>;
+
+    /// Draws each column of the dataset as lines, colored from [`dataset::PALETTE`]
+    /// unless given a color, and lists the columns in the legend.
+    fn plot_dataset(&'a mut self, dataset: &'a Dataset) -> &'a mut Chart<'a>;
 }
 
 /// Provides an interface for drawing colored plots.
 pub trait ColorPlot<'a> {
     /// Draws a [line chart](https://en.wikipedia.org/wiki/Line_chart) of points connected by straight line segments using the specified color
-    fn linecolorplot(&'a mut self, shape: &'a Shape, color: RGB8) -> &'a mut Chart;
+    fn linecolorplot(&'a mut self, shape: &'a Shape, color: RGB8) -> &'a mut Chart<'a>;
+
+    /// Same as `linecolorplot`, but the shape is scaled against the secondary y-axis.
+    fn linecolorplot_secondary(&'a mut self, shape: &'a Shape, color: RGB8) -> &'a mut Chart<'a>;
+
+    /// Same as `customplot`, but the geometry is drawn using the specified color
+    /// unless it picks colors on its own.
+    fn customcolorplot(&'a mut self, drawable: &'a dyn Drawable, color: RGB8) -> &'a mut Chart<'a>;
+}
+
+/// User-defined geometry that can be plotted alongside the built-in shapes,
+/// for chart types textplots doesn't support out of the box.
+///
+/// ```rust
+/// use textplots::{Chart, DrawContext, Drawable, Plot};
+///
+/// /// Open, high, low and close prices of a single period.
+/// struct Candle(f32, f32, f32, f32, f32);
+///
+/// impl Drawable for Candle {
+///     fn draw(&self, ctx: &mut DrawContext) {
+///         let Candle(x, open, high, low, close) = *self;
+///         ctx.line((x, low), (x, high));
+///         ctx.rect((x - 0.2, open), (x + 0.2, close));
+///     }
+///
+///     fn y_bounds(&self) -> Option<(f32, f32)> {
+///         Some((self.3, self.2))
+///     }
+/// }
+///
+/// let first = Candle(1.0, 10.0, 14.0, 9.0, 12.0);
+/// let second = Candle(2.0, 12.0, 13.0, 8.0, 9.0);
+///
+/// Chart::new(120, 60, 0.0, 3.0)
+///     .customplot(&first)
+///     .customplot(&second)
+///     .display();
+/// ```
+pub trait Drawable {
+    /// Draws the geometry through the context.
+    fn draw(&self, ctx: &mut DrawContext);
+
+    /// Returns the smallest and largest y values of the geometry to be considered when
+    /// the y-axis is ranged automatically, or `None` to leave the range to the other shapes.
+    fn y_bounds(&self) -> Option<(f32, f32)> {
+        None
+    }
+}
+
+/// Drawing surface handed to a [`Drawable`], taking data coordinates.
+pub struct DrawContext<'c, 'a> {
+    chart: &'c mut Chart<'a>,
+    color: Option<RGB8>,
+}
+
+impl DrawContext<'_, '_> {
+    /// Returns the color the geometry was plotted with, if any.
+    pub fn color(&self) -> Option<RGB8> {
+        self.color
+    }
+
+    /// Changes the color the following geometry is drawn with, for geometry picking its own colors.
+    pub fn set_color(&mut self, color: Option<RGB8>) {
+        self.color = color;
+    }
+
+    /// Returns the x-axis range as `(xmin, xmax)`.
+    pub fn x_range(&self) -> (f32, f32) {
+        self.chart.x_range()
+    }
+
+    /// Returns the y-axis range as `(ymin, ymax)`.
+    pub fn y_range(&self) -> (f32, f32) {
+        self.chart.y_range()
+    }
+
+    /// Draws a line between two points, clipped to the chart area.
+    pub fn line(&mut self, p1: (f32, f32), p2: (f32, f32)) {
+        self.chart.draw_line(p1, p2, self.color);
+    }
+
+    /// Draws a single point, unless it lies outside of the chart area.
+    pub fn point(&mut self, p: (f32, f32)) {
+        self.chart.draw_point(p, self.color);
+    }
+
+    /// Draws the outline of a rectangle spanned by two opposite corners.
+    pub fn rect(&mut self, p1: (f32, f32), p2: (f32, f32)) {
+        self.chart.draw_rect(p1, p2, self.color);
+    }
 }
 
 /// Provides a builder interface for styling axis.
@@ -135,6 +778,55 @@ pub trait AxisBuilder<'a> {
 
     /// Specifies the style of y-axis.
     fn y_axis_style(&'a mut self, style: LineStyle) -> &'a mut Chart<'a>;
+
+    /// Specifies the color of both axis lines, so they can be dimmed or tinted apart from the data.
+    fn axis_color(&'a mut self, color: RGB8) -> &'a mut Chart<'a>;
+
+    /// Specifies whether the axes cross at the origin or are pinned to the chart edges.
+    fn axis_position(&'a mut self, position: AxisPosition) -> &'a mut Chart<'a>;
+
+    /// Specifies a fixed range of the secondary y-axis instead of calculating it automatically.
+    fn y2_range(&'a mut self, ymin: f32, ymax: f32) -> &'a mut Chart<'a>;
+
+    /// Specifies whether small arrowheads are drawn at the positive ends of the axes.
+    ///
+    /// ```rust
+    /// use textplots::{AxisBuilder, Chart, Plot, Shape};
+    ///
+    /// Chart::new_with_y_range(120, 60, -5.0, 5.0, -2.0, 2.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sin())))
+    ///     .axis_arrows(true)
+    ///     .origin_marker(true)
+    ///     .display();
+    /// ```
+    fn axis_arrows(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies whether a small ring is drawn where the axes cross.
+    fn origin_marker(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Breaks the y-axis, cutting the given range of values out of the chart and marking
+    /// the cut with a zig-zag, so that series far apart from each other stay readable.
+    ///
+    /// ```rust
+    /// use textplots::{AxisBuilder, Chart, Plot, Shape, TickDisplay, TickDisplayBuilder};
+    ///
+    /// let baseline = Shape::Continuous(Box::new(|x| x.sin()));
+    /// let offset = Shape::Continuous(Box::new(|x| 1000.0 + x.cos()));
+    ///
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .lineplot(&baseline)
+    ///     .lineplot(&offset)
+    ///     .y_break(2.0..998.0)
+    ///     .y_tick_display(TickDisplay::Sparse)
+    ///     .display();
+    /// ```
+    fn y_break(&'a mut self, gap: Range<f32>) -> &'a mut Chart<'a>;
+}
+
+/// Provides a builder interface for styling chart border.
+pub trait BorderBuilder<'a> {
+    /// Specifies the style of the border, which is then displayed by both `display` and `nice`.
+    fn border_style(&'a mut self, style: BorderStyle) -> &'a mut Chart<'a>;
 }
 
 pub trait LabelBuilder<'a> {
@@ -143,15 +835,460 @@ pub trait LabelBuilder<'a> {
 
     /// Specifies the label format of y-axis.
     fn y_label_format(&'a mut self, format: LabelFormat) -> &'a mut Chart<'a>;
+
+    /// Specifies the label format of the secondary y-axis.
+    fn y2_label_format(&'a mut self, format: LabelFormat) -> &'a mut Chart<'a>;
+
+    /// Specifies the side of the canvas the y-axis labels are displayed on.
+    fn y_label_position(&'a mut self, position: LabelPosition) -> &'a mut Chart<'a>;
+
+    /// Specifies the color of the axis labels.
+    fn label_color(&'a mut self, color: RGB8) -> &'a mut Chart<'a>;
+
+    /// Specifies whether the x value at the middle of the canvas is labeled between the xmin
+    /// and xmax labels. It is left out when there's no room for it, or with custom x ticks.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, Plot, Shape};
+    ///
+    /// let frame = Chart::new(120, 60, 0.0, 50.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sin())))
+    ///     .x_label_middle(true)
+    ///     .render();
+    ///
+    /// assert!(frame.lines().last().unwrap().contains("25.0"));
+    /// ```
+    fn x_label_middle(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies whether the last value of every series is printed right of the canvas, in the
+    /// row where the series ends and in its color, formatted like the labels of its y-axis.
+    /// Values ending up in the same row are moved to the nearest free one.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, Plot, Shape};
+    ///
+    /// let cpu = [(0.0, 35.0), (1.0, 42.0), (2.0, 61.5)];
+    ///
+    /// let frame = Chart::new_with_y_range(120, 60, 0.0, 2.0, 0.0, 100.0)
+    ///     .lineplot(&Shape::Lines(&cpu))
+    ///     .end_values(true)
+    ///     .render();
+    ///
+    /// assert!(frame.contains("61.5"));
+    /// ```
+    fn end_values(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+}
+
+/// Provides an interface for adding free text around the chart.
+pub trait TextBuilder<'a> {
+    /// Specifies text displayed above the chart. Lines are wrapped to the width of the chart,
+    /// and explicit line breaks are kept.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TextBuilder};
+    ///
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sqrt())))
+    ///     .caption("Figure 1: square root")
+    ///     .footer("Values are computed for every column of the canvas, so the curve is exact up to the resolution of the terminal.")
+    ///     .display();
+    /// ```
+    fn caption(&'a mut self, text: &str) -> &'a mut Chart<'a>;
+
+    /// Specifies text displayed under the x-axis labels, wrapped the same way as the caption.
+    fn footer(&'a mut self, text: &str) -> &'a mut Chart<'a>;
+
+    /// Specifies the title of the x-axis, centered under its labels.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TextBuilder};
+    ///
+    /// let sqrt = Shape::Continuous(Box::new(|x| x.sqrt()));
+    /// let mut chart = Chart::new(120, 60, 0.0, 100.0);
+    /// let chart = chart
+    ///     .lineplot(&sqrt)
+    ///     .x_title("size [MB]")
+    ///     .y_title("time [s]");
+    ///
+    /// let output = chart.to_string();
+    /// assert!(output.lines().next().unwrap().ends_with("time [s]"));
+    /// assert_eq!(output.lines().last().unwrap().trim(), "size [MB]");
+    /// ```
+    fn x_title(&'a mut self, text: &str) -> &'a mut Chart<'a>;
+
+    /// Specifies the title of the y-axis, displayed above its labels.
+    fn y_title(&'a mut self, text: &str) -> &'a mut Chart<'a>;
+
+    /// Specifies whether the series are listed under the chart, each with a marker in its color.
+    /// Series without a name are listed by their number.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, ColorPlot, SeriesBuilder, Shape, TextBuilder};
+    ///
+    /// let sin = Shape::Continuous(Box::new(|x| x.sin()));
+    /// let cos = Shape::Continuous(Box::new(|x| x.cos()));
+    /// let mut chart = Chart::new(120, 60, -5.0, 5.0);
+    /// let chart = chart
+    ///     .linecolorplot(&sin, rgb::RGB8::new(255, 0, 0))
+    ///     .series_name("sin")
+    ///     .linecolorplot(&cos, rgb::RGB8::new(0, 0, 255))
+    ///     .series_name("cos")
+    ///     .legend(true);
+    ///
+    /// assert!(chart.to_plain_string().ends_with("⣿ sin  ⣿ cos\n"));
+    /// ```
+    fn legend(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies whether a summary line with the minimum, maximum, mean and last value
+    /// is displayed under the chart for every series, along with the number of values.
+    /// Values are formatted with the label format of the axis the series is plotted against.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, SeriesBuilder, Shape, TextBuilder};
+    ///
+    /// let load = Shape::Lines(&[(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)]);
+    /// let mut chart = Chart::new(120, 60, 0.0, 2.0);
+    /// let chart = chart.lineplot(&load).series_name("load").with_stats(true);
+    ///
+    /// assert!(chart
+    ///     .to_string()
+    ///     .contains("load: min 1.0  max 3.0  mean 2.0  last 2.0  count 3"));
+    /// ```
+    fn with_stats(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Displays a vertical bar of the colormap to the right of the chart, the maximum at the top,
+    /// with the values at the top, in the middle, and at the bottom next to it, so colors
+    /// mapped from values can be read back.
+    ///
+    /// ```rust
+    /// use textplots::colormap::Colormap;
+    /// use textplots::{Chart, Plot, Shape, TextBuilder};
+    ///
+    /// let sine = Shape::Continuous(Box::new(|x| x.sin()));
+    /// let mut chart = Chart::new(120, 60, 0.0, 10.0);
+    /// let chart = chart
+    ///     .lineplot(&sine)
+    ///     .colorbar(Colormap::VIRIDIS, 0.0, 40.0);
+    ///
+    /// assert!(chart.to_string().lines().next().unwrap().ends_with(" 40"));
+    /// ```
+    fn colorbar(&'a mut self, map: Colormap, min: f32, max: f32) -> &'a mut Chart<'a>;
 }
 
-/// Provides an interface for adding tick labels to the y-axis
+/// Provides an interface for adding tick labels to the axes
 pub trait TickDisplayBuilder<'a> {
     // Horizontal labels don't allow for support of x-axis tick labels
     /// Specifies the tick label density of y-axis.
     /// TickDisplay::Sparse will change the canvas height to the nearest multiple of 16
     /// TickDisplay::Dense will change the canvas height to the nearest multiple of 8
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TickDisplay, TickDisplayBuilder};
+    ///
+    /// let shape = Shape::Lines(&[(0.0, 0.0), (6.0, 1.0)]);
+    /// let mut chart = Chart::new_with_y_range(40, 20, 0.0, 6.0, 0.0, 1.0);
+    /// let chart = chart.lineplot(&shape).y_tick_display(TickDisplay::Sparse);
+    ///
+    /// assert_eq!(chart.text_rows(), chart.render().lines().count());
+    /// ```
     fn y_tick_display(&'a mut self, density: TickDisplay) -> &'a mut Chart<'a>;
+
+    /// Same as `y_tick_display`, but keeps the canvas height as it is.
+    /// Tick labels are still placed every 4th or 2nd row, showing the values those rows actually represent.
+    fn y_tick_display_keep_height(&'a mut self, density: TickDisplay) -> &'a mut Chart<'a>;
+
+    /// Specifies exact x-axis tick values, replacing the xmin and xmax labels.
+    /// Labels are formatted with the x-axis label format, and overlapping ones are skipped.
+    fn x_ticks(&'a mut self, ticks: &[f32]) -> &'a mut Chart<'a>;
+
+    /// Specifies exact y-axis tick values, replacing the ymin, ymax and `TickDisplay` labels.
+    /// Every label is shown on the row the value falls into, formatted with the y-axis label format.
+    fn y_ticks(&'a mut self, ticks: &[f32]) -> &'a mut Chart<'a>;
+
+    /// Same as `x_ticks`, but every tick is shown with its own label.
+    fn x_ticks_labeled(&'a mut self, ticks: &[(f32, &str)]) -> &'a mut Chart<'a>;
+
+    /// Same as `y_ticks`, but every tick is shown with its own label.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TickDisplayBuilder};
+    ///
+    /// let latency = Shape::Continuous(Box::new(|x| 150.0 + 100.0 * x.sin()));
+    /// let mut chart = Chart::new_with_y_range(120, 60, 0.0, 10.0, 0.0, 300.0);
+    /// let chart = chart
+    ///     .lineplot(&latency)
+    ///     .y_ticks_labeled(&[(0.0, "0ms"), (200.0, "SLO 200ms"), (300.0, "300ms")]);
+    ///
+    /// assert!(chart.to_string().contains("SLO 200ms"));
+    /// ```
+    fn y_ticks_labeled(&'a mut self, ticks: &[(f32, &str)]) -> &'a mut Chart<'a>;
+
+    /// Treats x values as category indices, labeling each category under the interval
+    /// from its index to the next one, which matches bars made by [`utils::category_bars`].
+    /// Labels too long for the space of their category are abbreviated.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TickDisplayBuilder};
+    ///
+    /// let sales = Shape::Bars(&[(0.0, 12.0), (1.0, 12.0), (2.0, 7.0), (3.0, 9.0)]);
+    /// let mut chart = Chart::new_with_y_range(64, 40, 0.0, 3.0, 0.0, 15.0);
+    /// let chart = chart
+    ///     .lineplot(&sales)
+    ///     .x_categories(&["apples", "blackberries", "cherries"]);
+    ///
+    /// assert!(chart.to_string().contains("apples    blackberr… cherries"));
+    /// ```
+    fn x_categories(&'a mut self, labels: &[&str]) -> &'a mut Chart<'a>;
+
+    /// Specifies the number of small notches drawn along the axes between every two
+    /// labeled ticks, to make long axes easier to read without more labels.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TickDisplay, TickDisplayBuilder};
+    ///
+    /// Chart::new(120, 64, 0.0, 100.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sqrt())))
+    ///     .x_ticks(&[0.0, 50.0, 100.0])
+    ///     .y_tick_display(TickDisplay::Sparse)
+    ///     .minor_ticks(4)
+    ///     .display();
+    /// ```
+    fn minor_ticks(&'a mut self, n: u32) -> &'a mut Chart<'a>;
+}
+
+/// Provides an interface for styling how shapes are drawn.
+pub trait ShapeStyleBuilder<'a> {
+    /// Specifies the y value `Shape::Bars` are drawn from, e.g. a reference level of 100%.
+    /// Bars are drawn from zero by default, going up for positive values and down for negative ones.
+    /// A baseline outside of the y range is clamped to the nearest canvas edge.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, ShapeStyleBuilder};
+    ///
+    /// let bars = Shape::Bars(&[(0.0, -3.0), (1.0, 2.0)]);
+    /// // the bars are drawn in the 6th and the 16th text column
+    /// let drawn = |frame: String, row: usize| {
+    ///     let row: Vec<_> = frame.lines().nth(row).unwrap().chars().collect();
+    ///     (row[5] != '\u{2800}', row[15] != '\u{2800}')
+    /// };
+    ///
+    /// let mut chart = Chart::new(40, 24, -0.5, 1.5);
+    /// let frame = chart.lineplot(&bars).render();
+    /// // the zero axis is on the 3rd row, the negative bar goes below it and the positive above
+    /// assert!(drawn(frame.clone(), 0).1);
+    /// assert_eq!((true, false), drawn(frame, 4));
+    ///
+    /// let mut chart = Chart::new(40, 24, -0.5, 1.5);
+    /// let frame = chart.lineplot(&bars).baseline_bottom().render();
+    /// // both bars go up from the bottom
+    /// assert_eq!((true, true), drawn(frame, 4));
+    /// ```
+    fn baseline(&'a mut self, y: f32) -> &'a mut Chart<'a>;
+
+    /// Makes `Shape::Bars` always go up from the bottom of the canvas (ymin), whatever the values.
+    fn baseline_bottom(&'a mut self) -> &'a mut Chart<'a>;
+
+    /// Specifies where the horizontal segments of `Shape::Steps` are placed relative to the points.
+    fn step_style(&'a mut self, style: StepStyle) -> &'a mut Chart<'a>;
+
+    /// Specifies how `Shape::Lines` connect consecutive points, smoothing sparse data
+    /// with a spline instead of straight segments.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Interpolation, Plot, Shape, ShapeStyleBuilder};
+    ///
+    /// let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0), (3.0, 5.0), (4.0, 4.0)];
+    ///
+    /// Chart::new(120, 60, 0.0, 4.0)
+    ///     .lineplot(&Shape::Lines(&points))
+    ///     .interpolation(Interpolation::Monotone)
+    ///     .display();
+    /// ```
+    fn interpolation(&'a mut self, interpolation: Interpolation) -> &'a mut Chart<'a>;
+
+    /// Specifies whether `Shape::Polygon` is filled, following the even-odd rule,
+    /// instead of only being outlined.
+    fn polygon_fill(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies whether the missing values of `Shape::Gapped` are shown as hatched columns
+    /// spanning the gap, telling "no data" apart from data that's just not plotted.
+    fn shade_gaps(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies the color of canvas cells shared by several colored series, see
+    /// [`ColorMixing`].
+    ///
+    /// ```rust
+    /// use rgb::RGB8;
+    /// use textplots::{Chart, ColorMixing, ColorPlot, Shape, ShapeStyleBuilder};
+    /// # colored::control::set_override(true);
+    /// # std::env::set_var("COLORTERM", "truecolor");
+    ///
+    /// let rising = Shape::Lines(&[(0.0, 0.0), (10.0, 10.0)]);
+    /// let falling = Shape::Lines(&[(0.0, 10.0), (10.0, 0.0)]);
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 10.0);
+    /// let frame = chart
+    ///     .linecolorplot(&rising, RGB8::new(255, 0, 0))
+    ///     .linecolorplot(&falling, RGB8::new(0, 0, 255))
+    ///     .color_mixing(ColorMixing::Blend)
+    ///     .render();
+    ///
+    /// // the lines cross in purple
+    /// assert!(frame.contains("\u{1b}[38;2;127;0;127m"));
+    /// ```
+    fn color_mixing(&'a mut self, mixing: ColorMixing) -> &'a mut Chart<'a>;
+}
+
+/// Provides an interface for controlling how values are scaled on the canvas.
+pub trait ScaleBuilder<'a> {
+    /// Enables or disables normalized overlay mode, in which every series is scaled independently
+    /// to fill the whole canvas height. It's useful for comparing the shape of series with very
+    /// different magnitudes. Y-axis labels and the x-axis line are not displayed in this mode,
+    /// since they would be different for every series.
+    fn normalized(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies the fraction of the data range to add above and below automatically ranged
+    /// y-axes, so the extrema don't touch the top and bottom of the canvas. Default value is 0.
+    ///
+    /// Flat data has no range to take a fraction of, so it's padded by the fraction of its
+    /// value instead, and by half of its value (or 0.5 around zero) without any padding.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, ScaleBuilder, Shape};
+    ///
+    /// let readings = [(0.0, 42.0), (1.0, 42.0), (2.0, 42.0)];
+    /// let shape = Shape::Points(&readings);
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 2.0);
+    /// let chart = chart.lineplot(&shape).y_padding(0.1);
+    ///
+    /// let (ymin, ymax) = chart.y_range();
+    /// assert!((ymin - 37.8).abs() < 1e-4 && (ymax - 46.2).abs() < 1e-4);
+    /// assert!(!chart.is_degenerate());
+    /// ```
+    fn y_padding(&'a mut self, fraction: f32) -> &'a mut Chart<'a>;
+}
+
+/// Provides an interface for controlling how shapes are sampled before drawing.
+pub trait SamplingBuilder<'a> {
+    /// Enables or disables downsampling of `Points` and `Lines` shapes.
+    /// When enabled (the default), consecutive points falling into the same canvas column
+    /// are reduced to the first, minimum, maximum and last of them, and duplicate scatter
+    /// points are drawn only once. The rendered output is the same, but plotting series with
+    /// far more points than the canvas width is much faster.
+    fn downsample(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+
+    /// Specifies how far (as a fraction of the canvas height) a `Shape::Continuous` has to jump
+    /// between neighbouring columns before it's checked for a discontinuity, such as the
+    /// asymptotes of tan(x) or 1/x. The line is broken there instead of drawing a tall vertical
+    /// stroke. Default value is 0.5, and 1.0 or more never breaks the line.
+    fn jump_threshold(&'a mut self, fraction: f32) -> &'a mut Chart<'a>;
+
+    /// Specifies how many samples `Shape::Continuous` takes within every canvas column. Default
+    /// value is 1, which connects one sample per column with lines. With more, every column is
+    /// drawn as a bar from the smallest to the largest of its samples, which shows the envelope
+    /// of functions oscillating faster than the canvas resolution, at the cost of evaluating
+    /// them more often.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, SamplingBuilder, Shape};
+    ///
+    /// let chirp = Shape::Continuous(Box::new(|x| (x * x * 20.0).sin()));
+    /// let mut chart = Chart::new(120, 60, 0.0, 5.0);
+    ///
+    /// chart.lineplot(&chirp).samples_per_column(8).display();
+    /// ```
+    fn samples_per_column(&'a mut self, n: u32) -> &'a mut Chart<'a>;
+
+    /// Enables or disables drawing `Points` and `Lines` shapes as envelopes: every canvas
+    /// column gets a bar from the smallest to the largest value falling into it, instead of
+    /// the points being drawn or connected one by one. This is how audio editors show
+    /// waveforms, and it keeps series with millions of points readable and fast to draw.
+    /// Columns without any values are left empty, so it is meant for series denser than the
+    /// canvas.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, SamplingBuilder, Shape};
+    ///
+    /// let signal: Vec<(f32, f32)> = (0..100_000)
+    ///     .map(|i| {
+    ///         let t = i as f32 / 100_000.0;
+    ///         (t, (t * 3000.0).sin() * (t * 6.0).cos())
+    ///     })
+    ///     .collect();
+    ///
+    /// Chart::new(200, 40, 0.0, 1.0)
+    ///     .lineplot(&Shape::Lines(&signal))
+    ///     .envelope(true)
+    ///     .display();
+    /// ```
+    fn envelope(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+}
+
+/// Provides an interface for describing the plotted series.
+pub trait SeriesBuilder<'a> {
+    /// Names the most recently plotted series, see [`Chart::series`].
+    fn series_name(&'a mut self, name: &str) -> &'a mut Chart<'a>;
+
+    /// Sets the position of the most recently plotted series in the drawing order. Series
+    /// with a higher `z` are drawn over the others, which keep the order they were plotted in.
+    /// Default value is 0.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, ColorPlot, SeriesBuilder, Shape};
+    ///
+    /// let alert = Shape::Lines(&[(0.0, 1.0), (10.0, 9.0)]);
+    /// let noise = Shape::Lines(&[(0.0, 9.0), (10.0, 1.0)]);
+    ///
+    /// // the alert line stays on top where the two cross
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .linecolorplot(&alert, rgb::RGB8::new(255, 0, 0))
+    ///     .z_order(1)
+    ///     .linecolorplot(&noise, rgb::RGB8::new(128, 128, 128))
+    ///     .display();
+    /// ```
+    fn z_order(&'a mut self, z: i32) -> &'a mut Chart<'a>;
+
+    /// Enables or disables density rendering of the most recently plotted `Shape::Points`,
+    /// for scatter plots dense enough to overlap. Every point landing in a character cell
+    /// sets one more of its dots, and colored series get brighter with them, so crowded
+    /// regions show up hotter instead of as a uniform blob. Disabled by default.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, ColorPlot, SeriesBuilder, Shape};
+    ///
+    /// // a cluster around the origin, thinning out further away
+    /// let points: Vec<(f32, f32)> = (1..2000)
+    ///     .map(|i| {
+    ///         let r = (i as f32 / 2000.0).powi(3) * 5.0;
+    ///         let a = i as f32 * 2.4;
+    ///         (r * a.cos(), r * a.sin())
+    ///     })
+    ///     .collect();
+    ///
+    /// Chart::new(120, 60, -5.0, 5.0)
+    ///     .linecolorplot(&Shape::Points(&points), rgb::RGB8::new(200, 40, 40))
+    ///     .density(true)
+    ///     .display();
+    /// ```
+    fn density(&'a mut self, enabled: bool) -> &'a mut Chart<'a>;
+}
+
+/// Provides an interface for changing how the canvas itself is presented.
+pub trait CanvasBuilder<'a> {
+    /// Specifies the character printed for empty canvas cells. Default is U+2800
+    /// (BRAILLE PATTERN BLANK), but some fonts render it with visible dots,
+    /// so a space or any other fill glyph may be used instead.
+    ///
+    /// ```rust
+    /// use textplots::{CanvasBuilder, Chart, Plot, Shape};
+    ///
+    /// Chart::new(120, 60, -5.0, 5.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.cos())))
+    ///     .blank_char(' ')
+    ///     .display();
+    /// ```
+    fn blank_char(&'a mut self, c: char) -> &'a mut Chart<'a>;
 }
 
 impl<'a> Default for Chart<'a> {
@@ -162,7 +1299,12 @@ impl<'a> Default for Chart<'a> {
 
 /// Specifies line style.
 /// Default value is `LineStyle::Dotted`.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum LineStyle {
     /// Line is not displayed.
     None,
@@ -174,19 +1316,468 @@ pub enum LineStyle {
     Dashed,
 }
 
+/// Specifies border style.
+/// `Chart::nice` uses `BorderStyle::Dotted` unless another style is specified.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum BorderStyle {
+    /// Border is drawn on the canvas as a dotted line (⠁⠈⠀⠁).
+    Dotted,
+    /// Border is a box-drawing frame (┌─┐) around the canvas, with labels outside of it.
+    Box,
+}
+
+/// Specifies where the axes are drawn.
+/// Default value is `AxisPosition::Zero`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AxisPosition {
+    /// Axes cross at the origin, and are not displayed if zero is out of range.
+    Zero,
+    /// X-axis is drawn along the bottom edge and y-axis along the left edge,
+    /// with marks at custom tick positions.
+    Edge,
+}
+
+/// Specifies the color of canvas cells where colored series overlap, as the dots of a
+/// character cell can only have one color. Default value is `ColorMixing::Last`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ColorMixing {
+    /// The series drawn last colors the cell.
+    Last,
+    /// The series drawn first keeps the cell colored.
+    First,
+    /// The colors of the series are averaged, so red and blue lines cross in purple.
+    Blend,
+}
+
+/// Specifies how `Shape::Steps` connect consecutive points.
+/// Default value is `StepStyle::Pre`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum StepStyle {
+    /// Value changes at the previous point, each horizontal segment precedes its point.
+    Pre,
+    /// Value changes at the next point, each horizontal segment follows its point
+    /// (the usual way of plotting counters). The last value extends to the end of the chart.
+    Post,
+    /// Value changes halfway between the points.
+    Mid,
+}
+
+/// Specifies how `Shape::Lines` connect consecutive points.
+/// Default value is `Interpolation::Linear`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Interpolation {
+    /// Straight segments between the points.
+    Linear,
+    /// Catmull-Rom spline through the points, smooth but possibly overshooting them.
+    CatmullRom,
+    /// Monotone cubic spline through the points, which doesn't overshoot them.
+    /// Points with x values not strictly increasing are connected with straight segments.
+    Monotone,
+}
+
 /// Specifies label format.
 /// Default value is `LabelFormat::Value`.
+///
+/// ```rust
+/// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+///
+/// let shape = Shape::Lines(&[(0.0, 0.0), (1.0, 0.25)]);
+/// let mut chart = Chart::new_with_y_range(40, 8, 0.0, 1.0, 0.0, 0.25);
+/// let chart = chart
+///     .lineplot(&shape)
+///     .y_label_format(LabelFormat::Percent);
+///
+/// assert!(chart.to_string().contains("25.0%"));
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum LabelFormat {
     /// Label is not displayed.
     None,
     /// Label is shown as a value.
     Value,
-    /// Label is shown as a custom string.
+    /// Label is shown as a custom string. Can't be serialized.
+    ///
+    /// The string may be styled with escape sequences, like the ones `colored` adds,
+    /// to emphasize some of the ticks. They don't count towards the width of the label.
+    ///
+    /// ```rust
+    /// use colored::Colorize;
+    /// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+    ///
+    /// let points = [(0.0, 3.0), (1.0, 4.0), (2.0, 6.0), (3.0, 5.0)];
+    ///
+    /// Chart::new(120, 60, 0.0, 3.0)
+    ///     .lineplot(&Shape::Lines(&points))
+    ///     .x_label_format(LabelFormat::Custom(Box::new(|x| {
+    ///         if x == 3.0 {
+    ///             "today".bold().to_string()
+    ///         } else {
+    ///             format!("day {}", x)
+    ///         }
+    ///     })))
+    ///     .display();
+    /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
     Custom(Box<dyn Fn(f32) -> String>),
+    /// Label is shown in scientific notation with the given number of decimal places (1.2e4).
+    Scientific(usize),
+    /// Label is shown with an SI prefix (1.2k, 3.4M, 5.0m).
+    ///
+    /// The prefix is chosen for the rounded value, so 999950 is shown as 1.0M.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+    ///
+    /// let mut chart = Chart::new_with_y_range(120, 60, 0.0, 1.0, 0.0, 999_950.0);
+    /// let chart = chart
+    ///     .lineplot(&Shape::Lines(&[(0.0, 0.0), (1.0, 999_950.0)]))
+    ///     .y_label_format(LabelFormat::SiPrefix);
+    ///
+    /// assert!(chart.render().lines().next().unwrap().ends_with(" 1.0M"));
+    /// ```
+    SiPrefix,
+    /// Label is shown as a percentage of 1 (0.25 is shown as 25.0%).
+    Percent,
+    /// Label is shown as a number of bytes with a binary prefix (4.0KiB, 2.1GiB).
+    ///
+    /// The prefix is chosen for the rounded value, so 1048575 bytes are shown as 1.0MiB.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+    ///
+    /// let mut chart = Chart::new_with_y_range(120, 60, 0.0, 1.0, 0.0, 1_048_575.0);
+    /// let chart = chart
+    ///     .lineplot(&Shape::Lines(&[(0.0, 0.0), (1.0, 1_048_575.0)]))
+    ///     .y_label_format(LabelFormat::Bytes);
+    ///
+    /// assert!(chart.render().lines().next().unwrap().ends_with(" 1.0MiB"));
+    /// ```
+    Bytes,
+    /// Label is shown as a value with the number of decimal places chosen from the axis range
+    /// (3 decimals for a range 0.01 wide, none for a range 10000 wide).
+    Auto,
+    /// Label is shown as a custom string made from the tick and the axis it is on,
+    /// e.g. to print the unit on the last tick only. Can't be serialized.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+    ///
+    /// Chart::new(120, 60, 0.0, 100.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x * x)))
+    ///     .y_label_format(LabelFormat::Contextual(Box::new(|tick| {
+    ///         if tick.index + 1 == tick.count {
+    ///             format!("{:.0} m²", tick.value)
+    ///         } else {
+    ///             format!("{:.0}", tick.value)
+    ///         }
+    ///     })))
+    ///     .display();
+    /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Contextual(Box<dyn Fn(TickContext) -> String>),
+    /// Label is shown as a duration of the value in seconds (250ms, 45s, 1m30s, 2h05m, 3d04h).
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use textplots::value::points;
+    /// use textplots::{Chart, LabelBuilder, LabelFormat, Plot, Shape};
+    ///
+    /// let uptime: Vec<(Duration, f32)> = (0..48)
+    ///     .map(|m| (Duration::from_secs(m * 300), (m % 12) as f32))
+    ///     .collect();
+    /// let points = points(uptime);
+    ///
+    /// Chart::new(120, 60, 0.0, 14400.0)
+    ///     .lineplot(&Shape::Steps(&points))
+    ///     .x_label_format(LabelFormat::Duration)
+    ///     .display();
+    /// ```
+    Duration,
+}
+
+impl std::fmt::Debug for LabelFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            LabelFormat::None => f.write_str("None"),
+            LabelFormat::Value => f.write_str("Value"),
+            LabelFormat::Custom(_) => f.write_str("Custom(..)"),
+            LabelFormat::Contextual(_) => f.write_str("Contextual(..)"),
+            LabelFormat::Scientific(precision) => write!(f, "Scientific({})", precision),
+            LabelFormat::SiPrefix => f.write_str("SiPrefix"),
+            LabelFormat::Percent => f.write_str("Percent"),
+            LabelFormat::Bytes => f.write_str("Bytes"),
+            LabelFormat::Auto => f.write_str("Auto"),
+            LabelFormat::Duration => f.write_str("Duration"),
+        }
+    }
+}
+
+impl LabelFormat {
+    /// Returns a copy of the format, or `None` for a custom one as closures can't be copied.
+    fn try_clone(&self) -> Option<LabelFormat> {
+        Some(match self {
+            LabelFormat::None => LabelFormat::None,
+            LabelFormat::Value => LabelFormat::Value,
+            LabelFormat::Custom(_) | LabelFormat::Contextual(_) => return None,
+            LabelFormat::Scientific(precision) => LabelFormat::Scientific(*precision),
+            LabelFormat::SiPrefix => LabelFormat::SiPrefix,
+            LabelFormat::Percent => LabelFormat::Percent,
+            LabelFormat::Bytes => LabelFormat::Bytes,
+            LabelFormat::Auto => LabelFormat::Auto,
+            LabelFormat::Duration => LabelFormat::Duration,
+        })
+    }
+
+    /// Formats the tick according to the label format.
+    fn format(&self, tick: TickContext) -> String {
+        let value = tick.value;
+        let range = tick.range.1 - tick.range.0;
+        match self {
+            LabelFormat::None => "".to_owned(),
+            LabelFormat::Value => format!("{:.1}", value),
+            LabelFormat::Custom(f) => f(value),
+            LabelFormat::Contextual(f) => f(tick),
+            LabelFormat::Scientific(precision) => format!("{:.*e}", precision, value),
+            LabelFormat::SiPrefix => format_si_prefix(value),
+            LabelFormat::Percent => format!("{:.1}%", value * 100.0),
+            LabelFormat::Bytes => format_bytes(value),
+            LabelFormat::Auto => format!("{:.*}", auto_precision(range), value),
+            LabelFormat::Duration => format_duration(value),
+        }
+    }
+
+    /// Formats a value that isn't one of the ticks, like a statistic, on an axis
+    /// spanning the range.
+    fn format_value(&self, value: f32, range: (f32, f32)) -> String {
+        self.format(TickContext {
+            value,
+            index: 0,
+            count: 1,
+            range,
+        })
+    }
+
+    /// Formats the ticks of an axis spanning the range, given in increasing order.
+    fn format_ticks(&self, values: &[f32], range: (f32, f32)) -> Vec<String> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| {
+                self.format(TickContext {
+                    value,
+                    index,
+                    count: values.len(),
+                    range,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The tick being labeled, passed to `LabelFormat::Contextual`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TickContext {
+    /// Value at the tick.
+    pub value: f32,
+    /// Position of the tick among the labeled ticks of the axis, counting from the smallest value.
+    pub index: usize,
+    /// Number of labeled ticks of the axis.
+    pub count: usize,
+    /// Smallest and largest value of the axis.
+    pub range: (f32, f32),
+}
+
+/// Returns the canvas positions of `count` notches evenly spaced between every two
+/// neighbouring ticks at the given positions.
+fn minor_tick_positions(ticks: &mut [f32], count: u32) -> Vec<u32> {
+    ticks.sort_by(|a, b| a.total_cmp(b));
+    ticks
+        .windows(2)
+        .flat_map(|pair| {
+            let step = (pair[1] - pair[0]) / (count + 1) as f32;
+            (1..=count).map(move |k| (pair[0] + step * k as f32).round() as u32)
+        })
+        .collect()
+}
+
+/// Iterates over the points of the data of a shape, leaving out missing values.
+/// Continuous shapes and outlines given by their size have none.
+fn data_points<'s>(shape: &'s Shape) -> Box<dyn DoubleEndedIterator<Item = (f32, f32)> + 's> {
+    match shape {
+        Shape::Points(dt)
+        | Shape::Lines(dt)
+        | Shape::Steps(dt)
+        | Shape::Bars(dt)
+        | Shape::Polygon(dt)
+        | Shape::Bezier(dt) => Box::new(dt.iter().copied()),
+        Shape::ColoredPoints(dt) => Box::new(dt.iter().map(|&(x, y, _)| (x, y))),
+        Shape::Bubbles(dt) => Box::new(dt.iter().map(|&(x, y, _)| (x, y))),
+        Shape::LabeledPoints(dt) => Box::new(dt.iter().map(|&(x, y, _)| (x, y))),
+        Shape::Gapped(dt) => Box::new(dt.iter().filter_map(|&(x, y)| Some((x, y?)))),
+        Shape::Continuous(_)
+        | Shape::ParallelContinuous(_)
+        | Shape::Circle { .. }
+        | Shape::Ellipse { .. } => Box::new(iter::empty()),
+    }
+}
+
+/// Returns the context of the tick at the value among the ticks at the values.
+fn tick_context(values: &[f32], value: f32, range: (f32, f32)) -> TickContext {
+    TickContext {
+        value,
+        index: values.iter().filter(|&&v| v < value).count(),
+        count: values.len(),
+        range,
+    }
+}
+
+/// Chooses the number of decimal places needed to tell apart values on an axis of the given width.
+fn auto_precision(range: f32) -> usize {
+    let range = range.abs();
+    if range == 0.0 || !range.is_finite() {
+        return 1;
+    }
+
+    (1.0 - range.log10().floor()).clamp(0.0, 6.0) as usize
+}
+
+/// Formats the value scaled to the closest SI prefix.
+fn format_si_prefix(value: f32) -> String {
+    const PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+
+    if value == 0.0 || !value.is_finite() {
+        return format!("{:.1}", value);
+    }
+
+    let exponent = (value.abs().log10() / 3.0).floor() as i32;
+    let mut index = (exponent + 4).clamp(0, PREFIXES.len() as i32 - 1);
+    let mut scaled = value / 1000_f32.powi(index - 4);
+    // rounding may carry the value over to the next prefix, 999.95k is shown as 1.0M
+    if (scaled.abs() * 10.0).round() >= 10_000.0 && index < PREFIXES.len() as i32 - 1 {
+        index += 1;
+        scaled /= 1000.0;
+    }
+
+    format!("{:.1}{}", scaled, PREFIXES[index as usize])
+}
+
+/// Formats the value as a number of bytes scaled to the closest binary prefix.
+fn format_bytes(value: f32) -> String {
+    const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    // values are rounded to whole bytes and to tenths of the larger units, which may carry
+    // them over to the next unit, 1023.95KiB is shown as 1.0MiB
+    let rounded = |scaled: f32, index| match index {
+        0 => scaled.abs().round(),
+        _ => (scaled.abs() * 10.0).round() / 10.0,
+    };
+
+    let mut scaled = value;
+    let mut index = 0;
+    while rounded(scaled, index) >= 1024.0 && index < UNITS.len() - 1 {
+        scaled /= 1024.0;
+        index += 1;
+    }
+
+    if index == 0 {
+        format!("{:.0}{}", scaled, UNITS[index])
+    } else {
+        format!("{:.1}{}", scaled, UNITS[index])
+    }
+}
+
+/// Formats the value in seconds as a duration of its two largest units.
+fn format_duration(value: f32) -> String {
+    if !value.is_finite() {
+        return format!("{:.1}", value);
+    }
+
+    let sign = if value < 0.0 { "-" } else { "" };
+    let seconds = value.abs();
+    if seconds > 0.0 && seconds < 1.0 {
+        return format!("{}{:.0}ms", sign, seconds * 1000.0);
+    }
+
+    let seconds = seconds.round() as u64;
+    let (days, hours, minutes) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60);
+    if days > 0 {
+        format!("{}{}d{:02}h", sign, days, hours)
+    } else if hours > 0 {
+        format!("{}{}h{:02}m", sign, hours, minutes)
+    } else if minutes > 0 {
+        format!("{}{}m{:02}s", sign, minutes, seconds % 60)
+    } else {
+        format!("{}{}s", sign, seconds)
+    }
+}
+
+/// Specifies on which side of the canvas the y-axis labels are displayed.
+/// Default value is `LabelPosition::Right`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum LabelPosition {
+    /// Labels are appended after each row.
+    Right,
+    /// Labels are right-aligned in a fixed-width gutter to the left of the canvas.
+    Left,
+    /// Labels are displayed on both sides of the canvas, unless there is a secondary y-axis,
+    /// which then takes the right side.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, LabelBuilder, LabelPosition, Plot, Shape};
+    ///
+    /// let frame = Chart::new_with_y_range(120, 60, 0.0, 10.0, -1.0, 1.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sin())))
+    ///     .y_label_position(LabelPosition::Both)
+    ///     .render();
+    ///
+    /// let top = frame.lines().next().unwrap();
+    /// assert!(top.trim_start().starts_with("1.0 ") && top.ends_with(" 1.0"));
+    /// ```
+    Both,
 }
 
 /// Specifies density of labels on the Y axis between ymin and ymax.
 /// Default value is `TickDisplay::None`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum TickDisplay {
     /// Tick labels are not displayed.
     None,
@@ -208,66 +1799,280 @@ impl TickDisplay {
 
 impl<'a> Display for Chart<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        // get frame and replace space with U+2800 (BRAILLE PATTERN BLANK)
-        let mut frame = self.canvas.frame().replace(' ', "\u{2800}");
+        // get rows and replace space with the blank character, U+2800 (BRAILLE PATTERN BLANK) by default
+        let mut blank = [0; 4];
+        let blank = self.blank_char.encode_utf8(&mut blank);
+        let rows: Vec<String> = self
+            .canvas
+            .rows()
+            .iter()
+            .map(|row| row.replace(' ', blank))
+            .collect();
 
-        if let Some(idx) = frame.find('\n') {
-            let xmin = self.format_x_axis_tick(self.xmin);
-            let xmax = self.format_x_axis_tick(self.xmax);
+        if rows.len() < 2 {
+            return write!(f, "{}", rows.join("\n"));
+        }
 
-            frame.insert_str(idx, &format!(" {0}", self.format_y_axis_tick(self.ymax)));
+        let (left_labels, right_labels, gutter) = self.y_label_sides(rows.len());
+        let has_gutter = gutter.is_some();
+        let gutter = gutter.unwrap_or(0);
 
-            // Display y-axis ticks if requested
-            match self.y_tick_display {
-                TickDisplay::None => {}
-                TickDisplay::Sparse | TickDisplay::Dense => {
-                    let row_spacing: u32 = self.y_tick_display.get_row_spacing(); // Rows between ticks
-                    let num_steps: u32 = (self.height / 4) / row_spacing; // 4 dots per row of text
-                    let step_size = (self.ymax - self.ymin) / (num_steps) as f32;
-                    for i in 1..(num_steps) {
-                        if let Some(index) = frame
-                            .match_indices('\n')
-                            .collect::<Vec<(usize, &str)>>()
-                            .get((i * row_spacing) as usize)
-                        {
-                            frame.insert_str(
-                                index.0,
-                                &format!(
-                                    " {0}",
-                                    self.format_y_axis_tick(self.ymax - (step_size * i as f32))
-                                ),
-                            );
-                        }
+        let boxed = self.border_style == Some(BorderStyle::Box);
+        let indent = if has_gutter { gutter + 1 } else { 0 };
+        let (left, right) = if boxed { ("│", "│") } else { ("", "") };
+        let columns = visible_width(&rows[0]);
+
+        // render everything into a single buffer, so that it's written out at once
+        let row_len = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let label_len = right_labels.iter().map(|l| l.len()).max().unwrap_or(0);
+        let mut out = String::with_capacity((rows.len() + 3) * (row_len + 2 * label_len + 32));
+
+        let text_width = indent + columns + if boxed { 2 } else { 0 };
+        if let Some(caption) = &self.caption {
+            for line in wrap(caption, text_width) {
+                writeln!(out, "{}", line)?;
+            }
+        }
+
+        // the y-axis title goes over the primary labels
+        if let Some(title) = &self.y_title {
+            if matches!(self.y_label_position, LabelPosition::Right) {
+                push_spaces(&mut out, text_width + 1);
+            }
+            writeln!(out, "{}", self.paint(title))?;
+        }
+
+        if boxed {
+            push_spaces(&mut out, indent);
+            out.push('┌');
+            out.extend(iter::repeat_n('─', columns));
+            out.push_str("┐\n");
+        }
+
+        let colorbar = self.colorbar_cells(rows.len());
+        let end_values = self.end_value_cells(rows.len());
+        let end_width = end_values
+            .iter()
+            .map(|value| label_width(value))
+            .max()
+            .unwrap_or(0);
+        let right_width = right_labels
+            .iter()
+            .map(|label| label_width(label))
+            .max()
+            .unwrap_or(0);
+
+        for ((((row, left_label), right_label), bar), end_value) in rows
+            .iter()
+            .zip(&left_labels)
+            .zip(&right_labels)
+            .zip(&colorbar)
+            .zip(&end_values)
+        {
+            if has_gutter {
+                push_spaces(&mut out, gutter.saturating_sub(label_width(left_label)));
+                write!(out, "{} ", self.paint(left_label))?;
+            }
+            out.push_str(left);
+            out.push_str(row);
+            out.push_str(right);
+            if end_width > 0 && (!right_label.is_empty() || !bar.is_empty()) {
+                // keep the labels right of the values lined up
+                write!(out, " {}", end_value)?;
+                push_spaces(&mut out, end_width - label_width(end_value));
+            } else if !end_value.is_empty() {
+                write!(out, " {}", end_value)?;
+            }
+            if !right_label.is_empty() {
+                write!(out, " {}", self.paint(right_label))?;
+            }
+            if !bar.is_empty() {
+                // line the bar up past the widest label
+                let padding = if right_label.is_empty() {
+                    right_width + 1
+                } else {
+                    right_width - label_width(right_label)
+                };
+                push_spaces(&mut out, padding + 1);
+                out.push_str(bar);
+            }
+            out.push('\n');
+        }
+
+        if boxed {
+            push_spaces(&mut out, indent);
+            out.push('└');
+            out.extend(iter::repeat_n('─', columns));
+            out.push_str("┘\n");
+        }
+
+        // align x-axis labels with the canvas rather than the frame
+        let indent = if boxed { indent + 1 } else { indent };
+        // a chart stacked over another one leaves the x labels to it, see `layout::dual_pane`
+        if !self.hide_x_labels {
+            push_spaces(&mut out, indent);
+
+            if self.x_ticks.is_empty() {
+                // keep xmax right-aligned with the canvas, but never let it overlap xmin
+                let range = (self.xmin, self.xmax);
+                let values = if self.x_label_middle {
+                    vec![self.xmin, (self.xmin + self.xmax) / 2.0, self.xmax]
+                } else {
+                    vec![self.xmin, self.xmax]
+                };
+                let mut labels = self.format_x(|format| format.format_ticks(&values, range));
+                let xmax = labels.pop().unwrap_or_default();
+                let xmiddle = if self.x_label_middle {
+                    labels.pop()
+                } else {
+                    None
+                };
+                let xmin = labels.pop().unwrap_or_default();
+                let (xmin_width, xmax_width) = (label_width(&xmin), label_width(&xmax));
+                let width = ((self.width as usize) / 2).max(xmin_width + xmax_width + 1);
+                write!(out, "{}", self.paint(&xmin))?;
+                let mut used = xmin_width;
+
+                // center the middle label under the canvas, unless it would touch the others
+                if let Some(xmiddle) = xmiddle {
+                    let middle_width = label_width(&xmiddle);
+                    let start = (width / 2).saturating_sub(middle_width / 2);
+                    if start > used && start + middle_width < width - xmax_width {
+                        push_spaces(&mut out, start - used);
+                        write!(out, "{}", self.paint(&xmiddle))?;
+                        used = start + middle_width;
                     }
                 }
+
+                push_spaces(&mut out, width - xmax_width - used);
+                writeln!(out, "{}", self.paint(&xmax))?;
+            } else {
+                let line = self.custom_x_tick_line();
+                writeln!(out, "{}", self.paint(&line))?;
             }
+        }
 
-            frame.push_str(&format!(
-                " {0}\n{1: <width$}{2}\n",
-                self.format_y_axis_tick(self.ymin),
-                xmin,
-                xmax,
-                width = (self.width as usize) / 2 - xmax.len()
-            ));
+        if let Some(title) = &self.x_title {
+            push_spaces(
+                &mut out,
+                indent + (columns.saturating_sub(title.width())) / 2,
+            );
+            writeln!(out, "{}", self.paint(title))?;
+        }
+
+        if self.legend {
+            writeln!(out, "{}", self.legend_line())?;
+        }
+
+        if self.stats {
+            for line in self.stats_lines() {
+                writeln!(out, "{}", line)?;
+            }
+        }
+
+        if let Some(footer) = &self.footer {
+            for line in wrap(footer, text_width) {
+                writeln!(out, "{}", line)?;
+            }
+        }
+
+        f.write_str(&out)
+    }
+}
+
+/// Returns the display width of a canvas row, skipping color escape sequences.
+fn visible_width(row: &str) -> usize {
+    strip_escapes(row).count()
+}
+
+/// Returns the display width of a label, skipping the escape sequences it is styled with.
+fn label_width(label: &str) -> usize {
+    strip_escapes(label).map(|c| c.width().unwrap_or(0)).sum()
+}
+
+/// Iterates over the characters of the text, skipping color escape sequences.
+fn strip_escapes(text: &str) -> impl Iterator<Item = char> + '_ {
+    let mut escape = false;
+
+    text.chars().filter(move |&c| match c {
+        '\u{1b}' => {
+            escape = true;
+            false
+        }
+        'm' if escape => {
+            escape = false;
+            false
         }
-        write!(f, "{}", frame)
+        _ => !escape,
+    })
+}
+
+/// Splits the text into lines no wider than the given display width, breaking them between
+/// words where possible and keeping explicit line breaks.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.width() + 1 + word.width() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            for c in word.chars() {
+                if line.width() + c.width().unwrap_or(0) > width {
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push(c);
+            }
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Shortens the text to the given display width, marking the cut with an ellipsis.
+fn abbreviate(text: &str, width: usize) -> String {
+    if label_width(text) <= width {
+        return text.to_string();
+    }
+
+    // styling is dropped rather than cut in the middle of an escape sequence
+    let mut output = String::new();
+    for c in strip_escapes(text) {
+        if output.width() + c.width().unwrap_or(0) + 1 > width {
+            break;
+        }
+        output.push(c);
+    }
+    if width > 0 {
+        output.push('…');
     }
+    output
+}
+
+/// Raises the canvas size to the smallest one supported, telling whether it had to.
+fn clamp_size(width: u32, height: u32) -> (u32, u32, bool) {
+    let clamped = width < MIN_WIDTH || height < MIN_HEIGHT;
+    (width.max(MIN_WIDTH), height.max(MIN_HEIGHT), clamped)
+}
+
+/// Appends the given number of spaces to the buffer.
+fn push_spaces(out: &mut String, count: usize) {
+    out.extend(iter::repeat_n(' ', count));
 }
 
 impl<'a> Chart<'a> {
     /// Creates a new `Chart` object.
     ///
-    /// # Panics
-    ///
-    /// Panics if `width` is less than 32 or `height` is less than 3.
+    /// A `width` less than [`MIN_WIDTH`] or a `height` less than [`MIN_HEIGHT`] is raised
+    /// to the minimum, see `is_clamped`, so charts sized after a narrow terminal still render.
     pub fn new(width: u32, height: u32, xmin: f32, xmax: f32) -> Self {
-        if width < 32 {
-            panic!("width should be at least 32");
-        }
-
-        if height < 3 {
-            panic!("height should be at least 3");
-        }
+        let (width, height, clamped) = clamp_size(width, height);
 
         Self {
             xmin,
@@ -277,21 +2082,67 @@ impl<'a> Chart<'a> {
             y_ranging: ChartRangeMethod::AutoRange,
             width,
             height,
+            clamped,
+            data_y_range: (f32::INFINITY, f32::NEG_INFINITY),
+            data_y2_range: (f32::INFINITY, f32::NEG_INFINITY),
+            y_padding: 0.0,
+            y2min: f32::INFINITY,
+            y2max: f32::NEG_INFINITY,
+            y2_ranging: ChartRangeMethod::AutoRange,
             shapes: Vec::new(),
             canvas: BrailleCanvas::new(width, height),
+            border_style: None,
             x_style: LineStyle::Dotted,
             y_style: LineStyle::Dotted,
+            axis_position: AxisPosition::Zero,
+            axis_color: None,
+            label_color: None,
+            x_label_middle: false,
+            end_values: false,
+            hide_x_labels: false,
+            min_gutter: 0,
+            shared_x: None,
             x_label_format: LabelFormat::Value,
             y_label_format: LabelFormat::Value,
+            y2_label_format: LabelFormat::Value,
+            y_label_position: LabelPosition::Right,
             y_tick_display: TickDisplay::None,
+            x_ticks: Vec::new(),
+            y_ticks: Vec::new(),
+            minor_ticks: 0,
+            abbreviate_x_ticks: false,
+            caption: None,
+            footer: None,
+            stats: false,
+            x_title: None,
+            y_title: None,
+            legend: false,
+            colorbar: None,
+            step_style: StepStyle::Pre,
+            color_mixing: ColorMixing::Last,
+            interpolation: Interpolation::Linear,
+            polygon_fill: false,
+            shade_gaps: false,
+            baseline: Some(0.0),
+            normalized: false,
+            jump_threshold: 0.5,
+            samples_per_column: 1,
+            envelope: false,
+            downsample: true,
+            strict: None,
+            spans: Vec::new(),
+            drawables: Vec::new(),
+            blank_char: '\u{2800}',
+            axis_arrows: false,
+            origin_marker: false,
+            y_break: None,
+            series: Vec::new(),
         }
     }
 
     /// Creates a new `Chart` object with fixed y axis range.
     ///
-    /// # Panics
-    ///
-    /// Panics if `width` is less than 32 or `height` is less than 3.
+    /// The size is raised to the minimum like with `new`.
     pub fn new_with_y_range(
         width: u32,
         height: u32,
@@ -300,51 +2151,37 @@ impl<'a> Chart<'a> {
         ymin: f32,
         ymax: f32,
     ) -> Self {
-        if width < 32 {
-            panic!("width should be at least 32");
-        }
-
-        if height < 3 {
-            panic!("height should be at least 3");
-        }
-
         Self {
-            xmin,
-            xmax,
             ymin,
             ymax,
             y_ranging: ChartRangeMethod::FixedRange,
-            width,
-            height,
-            shapes: Vec::new(),
-            canvas: BrailleCanvas::new(width, height),
-            x_style: LineStyle::Dotted,
-            y_style: LineStyle::Dotted,
-            x_label_format: LabelFormat::Value,
-            y_label_format: LabelFormat::Value,
-            y_tick_display: TickDisplay::None,
+            ..Self::new(width, height, xmin, xmax)
         }
     }
 
     /// Displays bounding rect.
     fn borders(&mut self) {
+        if self.border_style != Some(BorderStyle::Dotted) {
+            return;
+        }
+
         let w = self.width;
         let h = self.height;
 
-        self.vline(0, LineStyle::Dotted);
-        self.vline(w, LineStyle::Dotted);
-        self.hline(0, LineStyle::Dotted);
-        self.hline(h, LineStyle::Dotted);
+        self.vline(0, LineStyle::Dotted, None);
+        self.vline(w, LineStyle::Dotted, None);
+        self.hline(0, LineStyle::Dotted, None);
+        self.hline(h, LineStyle::Dotted, None);
     }
 
     /// Draws vertical line of the specified style.
-    fn vline(&mut self, i: u32, mode: LineStyle) {
+    fn vline(&mut self, i: u32, mode: LineStyle, color: Option<RGB8>) {
         match mode {
             LineStyle::None => {}
             LineStyle::Solid => {
                 if i <= self.width {
                     for j in 0..=self.height {
-                        self.canvas.set(i, j);
+                        self.dot(i, j, color);
                     }
                 }
             }
@@ -352,7 +2189,7 @@ impl<'a> Chart<'a> {
                 if i <= self.width {
                     for j in 0..=self.height {
                         if j % 3 == 0 {
-                            self.canvas.set(i, j);
+                            self.dot(i, j, color);
                         }
                     }
                 }
@@ -361,8 +2198,8 @@ impl<'a> Chart<'a> {
                 if i <= self.width {
                     for j in 0..=self.height {
                         if j % 4 == 0 {
-                            self.canvas.set(i, j);
-                            self.canvas.set(i, j + 1);
+                            self.dot(i, j, color);
+                            self.dot(i, j + 1, color);
                         }
                     }
                 }
@@ -371,13 +2208,13 @@ impl<'a> Chart<'a> {
     }
 
     /// Draws horizontal line of the specified style.
-    fn hline(&mut self, j: u32, mode: LineStyle) {
+    fn hline(&mut self, j: u32, mode: LineStyle, color: Option<RGB8>) {
         match mode {
             LineStyle::None => {}
             LineStyle::Solid => {
                 if j <= self.height {
                     for i in 0..=self.width {
-                        self.canvas.set(i, self.height - j);
+                        self.dot(i, self.height - j, color);
                     }
                 }
             }
@@ -385,7 +2222,7 @@ impl<'a> Chart<'a> {
                 if j <= self.height {
                     for i in 0..=self.width {
                         if i % 3 == 0 {
-                            self.canvas.set(i, self.height - j);
+                            self.dot(i, self.height - j, color);
                         }
                     }
                 }
@@ -394,8 +2231,8 @@ impl<'a> Chart<'a> {
                 if j <= self.height {
                     for i in 0..=self.width {
                         if i % 4 == 0 {
-                            self.canvas.set(i, self.height - j);
-                            self.canvas.set(i + 1, self.height - j);
+                            self.dot(i, self.height - j, color);
+                            self.dot(i + 1, self.height - j, color);
                         }
                     }
                 }
@@ -403,17 +2240,57 @@ impl<'a> Chart<'a> {
         }
     }
 
+    /// Sets a single point, colored if the color is specified.
+    fn dot(&mut self, x: u32, y: u32, color: Option<RGB8>) {
+        match color {
+            Some(color) => self.canvas.set_colored(x, y, rgb_to_pixelcolor(&color)),
+            None => self.canvas.set(x, y),
+        }
+    }
+
     /// Prints canvas content.
     pub fn display(&mut self) {
+        println!("{}", self.render());
+    }
+
+    /// Draws the chart and returns it as a string instead of printing it, for targets without
+    /// a standard output like web terminals, or for writing the chart elsewhere.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let frame = Chart::new(120, 60, -5.0, 5.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.cos())))
+    ///     .render();
+    ///
+    /// assert!(frame.lines().last().unwrap().starts_with("-5.0"));
+    /// ```
+    pub fn render(&mut self) -> String {
+        if let Some(range) = self.shared_x.as_ref().map(SharedXAxis::range) {
+            if range != (self.xmin, self.xmax) {
+                self.set_x_range(range.0, range.1);
+            }
+        }
+
+        self.borders();
         self.axis();
         self.figures();
 
-        println!("{}", self);
+        if self.strict.is_some() {
+            let diagnostics = self.diagnostics();
+            if let (false, Some(report)) = (diagnostics.is_clean(), self.strict.as_mut()) {
+                report(&diagnostics);
+            }
+        }
+
+        self.to_string()
     }
 
     /// Prints canvas content with some additional visual elements (like borders).
     pub fn nice(&mut self) {
-        self.borders();
+        if self.border_style.is_none() {
+            self.border_style = Some(BorderStyle::Dotted);
+        }
         self.display();
     }
 
@@ -421,14 +2298,123 @@ impl<'a> Chart<'a> {
     pub fn axis(&mut self) {
         self.x_axis();
         self.y_axis();
+        self.minor_tick_marks();
+        self.y_break_marker();
+
+        if self.origin_marker {
+            if let (Some(i), Some(j)) = (self.y_axis_column(), self.x_axis_row()) {
+                for (di, dj) in [
+                    (-1, -1),
+                    (0, -1),
+                    (1, -1),
+                    (-1, 0),
+                    (1, 0),
+                    (-1, 1),
+                    (0, 1),
+                    (1, 1),
+                ] {
+                    let (x, y) = (i as i32 + di, j as i32 + dj);
+                    if x >= 0 && y >= 0 {
+                        self.dot(x as u32, y as u32, self.axis_color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the scale of the primary y-axis.
+    fn y_scale(&self) -> Scale {
+        let scale = Scale::new(self.ymin..self.ymax, 0.0..self.height as f32);
+        match &self.y_break {
+            Some(gap) => scale.with_break(gap.clone()),
+            None => scale,
+        }
+    }
+
+    /// Draws zig-zags across both edges of the canvas where the y-axis is broken.
+    fn y_break_marker(&mut self) {
+        let gap = match &self.y_break {
+            Some(gap) if gap.start > self.ymin && gap.end < self.ymax => gap.clone(),
+            _ => return,
+        };
+
+        let j = self.height - self.y_scale().linear(gap.start).round() as u32;
+        for i in 0..=6 {
+            let dj = [0, 1, 2, 1][i as usize % 4];
+            let j = (j + dj).saturating_sub(1);
+            self.dot(i, j, self.axis_color);
+            self.dot(self.width - i, j, self.axis_color);
+        }
+    }
+
+    /// Returns the canvas row the x-axis is drawn at, if it's drawn.
+    fn x_axis_row(&self) -> Option<u32> {
+        let y_scale = self.y_scale();
+
+        match self.axis_position {
+            AxisPosition::Zero if self.normalized => None,
+            AxisPosition::Zero if self.ymin <= 0.0 && self.ymax >= 0.0 => {
+                Some(self.height - y_scale.linear(0.0) as u32)
+            }
+            AxisPosition::Zero => None,
+            AxisPosition::Edge => Some(self.height),
+        }
+    }
+
+    /// Returns the canvas column the y-axis is drawn at, if it's drawn.
+    fn y_axis_column(&self) -> Option<u32> {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+
+        match self.axis_position {
+            AxisPosition::Zero if self.xmin <= 0.0 && self.xmax >= 0.0 => {
+                Some(x_scale.linear(0.0) as u32)
+            }
+            AxisPosition::Zero => None,
+            AxisPosition::Edge => Some(0),
+        }
     }
 
     /// Shows x-axis.
     pub fn x_axis(&mut self) {
-        let y_scale = Scale::new(self.ymin..self.ymax, 0.0..self.height as f32);
+        let y_scale = self.y_scale();
+
+        match self.axis_position {
+            AxisPosition::Zero if self.normalized => {}
+            AxisPosition::Zero => {
+                if self.ymin <= 0.0 && self.ymax >= 0.0 {
+                    self.hline(y_scale.linear(0.0) as u32, self.x_style, self.axis_color);
+                }
+            }
+            AxisPosition::Edge => {
+                self.hline(0, self.x_style, self.axis_color);
+
+                // mark custom ticks just above the axis
+                let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+                let ticks: Vec<u32> = self
+                    .x_ticks
+                    .iter()
+                    .filter(|(x, _)| *x >= self.xmin && *x <= self.xmax)
+                    .map(|(x, _)| x_scale.linear(*x).round() as u32)
+                    .collect();
+                for i in ticks {
+                    self.dot(i, self.height, self.axis_color);
+                    self.dot(i, self.height - 1, self.axis_color);
+                }
+            }
+        }
 
-        if self.ymin <= 0.0 && self.ymax >= 0.0 {
-            self.hline(y_scale.linear(0.0) as u32, self.x_style);
+        // point the arrowhead right, without reaching below the canvas
+        if let Some(j) = self.x_axis_row() {
+            if self.axis_arrows && !matches!(self.x_style, LineStyle::None) {
+                for d in 1..=2 {
+                    self.dot(self.width - d, j.saturating_sub(d), self.axis_color);
+                    self.dot(
+                        self.width - d,
+                        cmp::min(j + d, self.height),
+                        self.axis_color,
+                    );
+                }
+            }
         }
     }
 
@@ -436,54 +2422,563 @@ impl<'a> Chart<'a> {
     pub fn y_axis(&mut self) {
         let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
 
-        if self.xmin <= 0.0 && self.xmax >= 0.0 {
-            self.vline(x_scale.linear(0.0) as u32, self.y_style);
+        match self.axis_position {
+            AxisPosition::Zero => {
+                if self.xmin <= 0.0 && self.xmax >= 0.0 {
+                    self.vline(x_scale.linear(0.0) as u32, self.y_style, self.axis_color);
+                }
+            }
+            AxisPosition::Edge => {
+                self.vline(0, self.y_style, self.axis_color);
+
+                // mark custom ticks just right of the axis
+                let y_scale = self.y_scale();
+                let ticks: Vec<u32> = self
+                    .y_ticks
+                    .iter()
+                    .filter(|(y, _)| *y >= self.ymin && *y <= self.ymax)
+                    .map(|(y, _)| self.height - y_scale.linear(*y).round() as u32)
+                    .collect();
+                for j in ticks {
+                    self.dot(0, j, self.axis_color);
+                    self.dot(1, j, self.axis_color);
+                }
+            }
         }
-    }
 
-    /// Performs formatting of the x axis.
-    fn format_x_axis_tick(&self, value: f32) -> String {
-        match &self.x_label_format {
-            LabelFormat::None => "".to_owned(),
-            LabelFormat::Value => format!("{:.1}", value),
-            LabelFormat::Custom(f) => f(value),
+        // point the arrowhead up, without reaching past the left edge of the canvas
+        if let Some(i) = self.y_axis_column() {
+            if self.axis_arrows && !matches!(self.y_style, LineStyle::None) {
+                for d in 1..=2 {
+                    self.dot(i.saturating_sub(d), d, self.axis_color);
+                    self.dot(cmp::min(i + d, self.width), d, self.axis_color);
+                }
+            }
         }
     }
 
-    /// Performs formatting of the y axis.
-    fn format_y_axis_tick(&self, value: f32) -> String {
-        match &self.y_label_format {
-            LabelFormat::None => "".to_owned(),
-            LabelFormat::Value => format!("{:.1}", value),
-            LabelFormat::Custom(f) => f(value),
+    /// Draws notches evenly spaced between every two labeled ticks of each drawn axis,
+    /// on the inner side of the axis.
+    fn minor_tick_marks(&mut self) {
+        if self.minor_ticks == 0 {
+            return;
         }
-    }
 
-    // Shows figures.
-    pub fn figures(&mut self) {
-        for (shape, color) in &self.shapes {
+        if let Some(j) = self.x_axis_row() {
             let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
-            let y_scale = Scale::new(self.ymin..self.ymax, 0.0..self.height as f32);
-
-            // translate (x, y) points into screen coordinates
-            let points: Vec<_> = match shape {
-                Shape::Continuous(f) => (0..self.width)
-                    .filter_map(|i| {
-                        let x = x_scale.inv_linear(i as f32);
-                        let y = f(x);
-                        if y.is_normal() {
-                            let j = y_scale.linear(y).round();
-                            Some((i, self.height - j as u32))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
-                Shape::Points(dt) | Shape::Lines(dt) | Shape::Steps(dt) | Shape::Bars(dt) => dt
+            let mut ticks: Vec<f32> = if self.x_ticks.is_empty() && self.x_label_middle {
+                vec![0.0, self.width as f32 / 2.0, self.width as f32]
+            } else if self.x_ticks.is_empty() {
+                vec![0.0, self.width as f32]
+            } else {
+                self.x_ticks
                     .iter()
-                    .filter_map(|(x, y)| {
-                        let i = x_scale.linear(*x).round() as u32;
-                        let j = y_scale.linear(*y).round() as u32;
+                    .filter(|(x, _)| *x >= self.xmin && *x <= self.xmax)
+                    .map(|(x, _)| x_scale.linear(*x))
+                    .collect()
+            };
+            for i in minor_tick_positions(&mut ticks, self.minor_ticks) {
+                self.dot(i, j.saturating_sub(1), self.axis_color);
+            }
+        }
+
+        if let Some(i) = self.y_axis_column() {
+            let mut ticks: Vec<f32> = if self.y_ticks.is_empty() {
+                // labeled rows, see `row_tick_labels`
+                let rows = (self.height / 4 + 1) as usize;
+                let mut ticks = vec![0.0, self.height as f32];
+                if let TickDisplay::Sparse | TickDisplay::Dense = self.y_tick_display {
+                    let row_spacing = self.y_tick_display.get_row_spacing() as usize;
+                    ticks.extend(
+                        (row_spacing..rows - 1)
+                            .step_by(row_spacing)
+                            .map(|row| (row * 4) as f32),
+                    );
+                }
+                ticks
+            } else {
+                let y_scale = self.y_scale();
+                self.y_ticks
+                    .iter()
+                    .filter(|(y, _)| *y >= self.ymin && *y <= self.ymax)
+                    .map(|(y, _)| self.height as f32 - y_scale.linear(*y))
+                    .collect()
+            };
+            for j in minor_tick_positions(&mut ticks, self.minor_ticks) {
+                self.dot(cmp::min(i + 1, self.width), j, self.axis_color);
+            }
+        }
+    }
+
+    /// Performs formatting of a value on the y axis.
+    fn format_y_axis_value(&self, value: f32) -> String {
+        self.y_label_format
+            .format_value(value, (self.ymin, self.ymax))
+    }
+
+    /// Colors the label text with the label color, if the color is specified.
+    fn paint(&self, text: &str) -> String {
+        match self.label_color {
+            Some(color) if !text.is_empty() => {
+                text.truecolor(color.r, color.g, color.b).to_string()
+            }
+            _ => text.to_owned(),
+        }
+    }
+
+    /// Returns the y-axis labels displayed left and right of every row, along with the width
+    /// of the gutter holding the left ones, or `None` if there's no gutter.
+    fn y_label_sides(&self, rows: usize) -> (Vec<String>, Vec<String>, Option<usize>) {
+        let labels = self.y_tick_labels(rows);
+        let secondary = self.has_secondary();
+        let labels2 = if secondary {
+            self.y2_tick_labels(rows)
+        } else {
+            vec![String::new(); rows]
+        };
+
+        // secondary labels go on the side opposite to the primary ones
+        let (left_labels, right_labels) = match self.y_label_position {
+            LabelPosition::Right => (labels2, labels),
+            LabelPosition::Left => (labels, labels2),
+            LabelPosition::Both if secondary => (labels, labels2),
+            LabelPosition::Both => (labels.clone(), labels),
+        };
+        let has_gutter = secondary
+            || !matches!(self.y_label_position, LabelPosition::Right)
+            || self.min_gutter > 0;
+        let gutter = has_gutter.then(|| {
+            left_labels
+                .iter()
+                .map(|label| label_width(label))
+                .max()
+                .unwrap_or(0)
+                .max(self.min_gutter)
+        });
+
+        (left_labels, right_labels, gutter)
+    }
+
+    /// Returns the colorbar segment displayed next to every row, or empty strings without a colorbar.
+    fn colorbar_cells(&self, rows: usize) -> Vec<String> {
+        let Some((map, min, max)) = &self.colorbar else {
+            return vec![String::new(); rows];
+        };
+        let last = rows.saturating_sub(1).max(1) as f32;
+
+        (0..rows)
+            .map(|row| {
+                let t = 1.0 - row as f32 / last;
+                let color = map.map(t);
+                let mut cell = "██".truecolor(color.r, color.g, color.b).to_string();
+
+                let label = if row == 0 {
+                    Some(*max)
+                } else if row == rows / 2 {
+                    Some((min + max) / 2.0)
+                } else if row + 1 == rows {
+                    Some(*min)
+                } else {
+                    None
+                };
+                if let Some(value) = label {
+                    let label = LabelFormat::Auto.format_value(value, (*min, *max));
+                    write!(cell, " {}", self.paint(&label)).unwrap();
+                }
+                cell
+            })
+            .collect()
+    }
+
+    /// Returns the last values of the series displayed next to every row, or empty strings
+    /// where no series ends.
+    fn end_value_cells(&self, rows: usize) -> Vec<String> {
+        let mut cells = vec![String::new(); rows];
+        if !self.end_values || rows == 0 {
+            return cells;
+        }
+
+        for (series, info) in self.shapes.iter().zip(&self.series) {
+            let shape = series.shape.get();
+            let last = match shape.function() {
+                Some(_) => series.samples.iter().rev().find(|y| y.is_finite()).copied(),
+                None => data_points(&shape)
+                    .rev()
+                    .find(|(x, y)| *x >= self.xmin && *x <= self.xmax && y.is_finite())
+                    .map(|(_, y)| y),
+            };
+            let Some(value) = last else {
+                continue;
+            };
+
+            let (range, format) = match series.axis {
+                _ if self.normalized => {
+                    let bounds = self.series_bounds(&shape, &series.samples, &series.envelope);
+                    (bounds, &LabelFormat::Auto)
+                }
+                YAxis::Primary => ((self.ymin, self.ymax), &self.y_label_format),
+                YAxis::Secondary => ((self.y2min, self.y2max), &self.y2_label_format),
+            };
+            let y_scale = Scale::new(range.0..range.1, 0.0..self.height as f32);
+            let row = ((self.height as f32 - y_scale.linear(value).round()) / 4.0) as usize;
+
+            // the nearest free row, looking below first
+            let Some(row) = (0..rows)
+                .flat_map(|d| [row + d, row.wrapping_sub(d)])
+                .find(|&r| r < rows && cells[r].is_empty())
+            else {
+                break;
+            };
+
+            let label = format.format_value(value, range);
+            cells[row] = match info.color {
+                Some(color) => label.truecolor(color.r, color.g, color.b).to_string(),
+                None => self.paint(&label),
+            };
+        }
+
+        cells
+    }
+
+    /// Returns the legend listing every series, in the order they were plotted.
+    fn legend_line(&self) -> String {
+        let entries: Vec<String> = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(k, info)| {
+                let marker = match info.color {
+                    Some(color) => "⣿".truecolor(color.r, color.g, color.b).to_string(),
+                    None => "⣿".to_string(),
+                };
+                match &info.name {
+                    Some(name) => format!("{} {}", marker, name),
+                    None => format!("{} series {}", marker, k + 1),
+                }
+            })
+            .collect();
+
+        entries.join("  ")
+    }
+
+    /// Returns a summary line of every series, in the order they were plotted.
+    fn stats_lines(&self) -> Vec<String> {
+        self.shapes
+            .iter()
+            .zip(&self.series)
+            .enumerate()
+            .map(|(k, (series, info))| {
+                let shape = series.shape.get();
+                let values: Vec<f32> = match shape.function() {
+                    Some(_) => series.samples.clone(),
+                    None => self
+                        .drawn_points(&shape)
+                        .filter(|(x, _)| *x >= self.xmin && *x <= self.xmax)
+                        .map(|(_, y)| y)
+                        .collect(),
+                };
+                let values: Vec<f32> = values.into_iter().filter(|y| y.is_finite()).collect();
+
+                let name = match &info.name {
+                    Some(name) => name.clone(),
+                    None => format!("series {}", k + 1),
+                };
+                let name = match info.color {
+                    Some(color) => name.truecolor(color.r, color.g, color.b).to_string(),
+                    None => name,
+                };
+
+                let Some(&last) = values.last() else {
+                    return format!("{}: count 0", name);
+                };
+                let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+                let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                let mean = values.iter().sum::<f32>() / values.len() as f32;
+
+                let format = |value: f32| match series.axis {
+                    YAxis::Primary => self.format_y_axis_value(value),
+                    YAxis::Secondary => self
+                        .y2_label_format
+                        .format_value(value, (self.y2min, self.y2max)),
+                };
+                format!(
+                    "{}: min {}  max {}  mean {}  last {}  count {}",
+                    name,
+                    format(min),
+                    format(max),
+                    format(mean),
+                    format(last),
+                    values.len()
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the y-axis label of every text row of the frame, empty for rows without a tick.
+    fn y_tick_labels(&self, rows: usize) -> Vec<String> {
+        let mut labels = vec![String::new(); rows];
+
+        if self.normalized {
+            return labels;
+        }
+
+        if !self.y_ticks.is_empty() {
+            for (row, label) in self.custom_y_tick_rows() {
+                labels[row.min(rows - 1)] = label;
+            }
+            return labels;
+        }
+
+        self.row_tick_labels(
+            rows,
+            &self.y_label_format,
+            self.y_scale(),
+            (self.ymin, self.ymax),
+        )
+    }
+
+    /// Returns the label of every text row of the frame for an axis spanning the range,
+    /// labeling the top and bottom rows with its ends, and the rows in between with
+    /// the values of their top pixel row if ticks are displayed.
+    fn row_tick_labels(
+        &self,
+        rows: usize,
+        format: &LabelFormat,
+        y_scale: Scale,
+        (min, max): (f32, f32),
+    ) -> Vec<String> {
+        // collected bottom to top, so that ticks are in increasing order
+        let mut tick_rows = vec![rows - 1];
+        let mut values = vec![min];
+
+        // Display y-axis ticks if requested
+        if let TickDisplay::Sparse | TickDisplay::Dense = self.y_tick_display {
+            // label rows with the values of their top pixel row, which also works
+            // when the height isn't a multiple of the row spacing
+            let row_spacing = self.y_tick_display.get_row_spacing() as usize; // Rows between ticks
+            for row in (row_spacing..rows - 1).step_by(row_spacing).rev() {
+                let j = self.height as f32 - (row * 4) as f32; // 4 dots per row of text
+                tick_rows.push(row);
+                values.push(y_scale.inv_linear(j));
+            }
+        }
+        tick_rows.push(0);
+        values.push(max);
+
+        let mut labels = vec![String::new(); rows];
+        for (row, label) in tick_rows
+            .into_iter()
+            .zip(format.format_ticks(&values, (min, max)))
+        {
+            labels[row] = label;
+        }
+
+        labels
+    }
+
+    /// Returns whether any shape is plotted against the secondary y-axis.
+    fn has_secondary(&self) -> bool {
+        self.shapes
+            .iter()
+            .any(|series| series.axis == YAxis::Secondary)
+    }
+
+    /// Returns the secondary y-axis label of every text row of the frame, empty for rows without a tick.
+    fn y2_tick_labels(&self, rows: usize) -> Vec<String> {
+        if self.normalized {
+            return vec![String::new(); rows];
+        }
+
+        self.row_tick_labels(
+            rows,
+            &self.y2_label_format,
+            Scale::new(self.y2min..self.y2max, 0.0..self.height as f32),
+            (self.y2min, self.y2max),
+        )
+    }
+
+    /// Returns the text rows of custom y-axis ticks along with their labels.
+    /// Labels falling into the same row are joined together.
+    fn custom_y_tick_rows(&self) -> Vec<(usize, String)> {
+        let y_scale = self.y_scale();
+        let range = (self.ymin, self.ymax);
+        let values: Vec<f32> = self
+            .y_ticks
+            .iter()
+            .map(|(value, _)| *value)
+            .filter(|value| (self.ymin..=self.ymax).contains(value))
+            .collect();
+        let mut rows: Vec<(usize, String)> = Vec::new();
+
+        for (value, label) in &self.y_ticks {
+            if *value < self.ymin || *value > self.ymax {
+                continue;
+            }
+
+            let j = y_scale.linear(*value).round() as u32;
+            let row = ((self.height - j) / 4) as usize;
+            let label = match label {
+                Some(label) => label.clone(),
+                None => self
+                    .y_label_format
+                    .format(tick_context(&values, *value, range)),
+            };
+
+            match rows.iter_mut().find(|(r, _)| *r == row) {
+                Some((_, text)) => {
+                    text.push_str(", ");
+                    text.push_str(&label);
+                }
+                None => rows.push((row, label)),
+            }
+        }
+
+        rows
+    }
+
+    /// Returns the line of custom x-axis tick labels, each starting under its tick.
+    fn custom_x_tick_line(&self) -> String {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let columns = (self.width / 2 + 1) as usize;
+        let range = (self.xmin, self.xmax);
+        let values: Vec<f32> = self
+            .x_ticks
+            .iter()
+            .map(|(value, _)| *value)
+            .filter(|value| (self.xmin..=self.xmax).contains(value))
+            .collect();
+        let mut ticks: Vec<(usize, String)> = Vec::new();
+
+        for (value, label) in &self.x_ticks {
+            if *value < self.xmin || *value > self.xmax {
+                continue;
+            }
+
+            let column = (x_scale.linear(*value).round() as usize) / 2;
+            let label = match label {
+                Some(label) => label.clone(),
+                None => self.format_x(|format| format.format(tick_context(&values, *value, range))),
+            };
+            ticks.push((column, label));
+        }
+        ticks.sort_by_key(|(column, _)| *column);
+
+        if self.abbreviate_x_ticks {
+            let starts: Vec<usize> = ticks.iter().map(|(column, _)| *column).collect();
+            for (k, (column, label)) in ticks.iter_mut().enumerate() {
+                let next = starts.get(k + 1).copied().unwrap_or(columns + 1);
+                *label = abbreviate(label, next.saturating_sub(*column + 1));
+            }
+        }
+
+        let mut line = String::new();
+        let mut free = 0;
+        for (column, label) in ticks {
+            let len = label_width(&label);
+            let start = column.min(columns.saturating_sub(len));
+            if start < free || start + len > columns {
+                continue;
+            }
+
+            line.push_str(&" ".repeat(start - label_width(&line)));
+            line.push_str(&label);
+            free = start + len + 1;
+        }
+
+        line
+    }
+
+    // Shows figures.
+    pub fn figures(&mut self) {
+        // spans go first, so that the shapes are drawn over them
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let y_scale = self.y_scale();
+        let full = |size: u32| Some(0..=size);
+        for (span, color) in &self.spans {
+            let (columns, rows) = match span {
+                Span::Horizontal(y) => (full(self.width), span_pixels(&y_scale, y, self.height)),
+                Span::Vertical(x) => (span_pixels(&x_scale, x, self.width), full(self.height)),
+            };
+            if let (Some(columns), Some(rows)) = (columns, rows) {
+                // rows count from the top of the canvas
+                let rows = self.height - rows.end()..=self.height - rows.start();
+                hatch(&mut self.canvas, columns, rows, color);
+            }
+        }
+
+        // draw series with higher z over the others, keeping the plotting order otherwise
+        let mut order: Vec<usize> = (0..self.shapes.len()).collect();
+        order.sort_by_key(|&k| self.shapes[k].z);
+
+        // unless the last series wins, colored series are drawn on a layer of their own,
+        // which is then merged into the canvas put aside meanwhile
+        let mut layer: Option<(BrailleCanvas, RGB8)> = None;
+        let mut cells = HashMap::new();
+        // labels are written once everything is drawn, so that they can avoid it
+        let mut labels = Vec::new();
+
+        for Series {
+            shape,
+            color,
+            axis,
+            samples,
+            envelope,
+            density,
+            ..
+        } in order.iter().map(|&k| &self.shapes[k])
+        {
+            if let Some((canvas, color)) = layer.take() {
+                let layer = mem::replace(&mut self.canvas, canvas);
+                let (mixing, size) = (self.color_mixing, (self.width, self.height));
+                merge_layer(&mut self.canvas, &layer, size, color, mixing, &mut cells);
+            }
+            if let (Some(color), false) = (color, self.color_mixing == ColorMixing::Last) {
+                let canvas = BrailleCanvas::new(self.width, self.height);
+                layer = Some((mem::replace(&mut self.canvas, canvas), *color));
+            }
+
+            let shape = shape.get();
+            let shape: &Shape = &shape;
+            let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+            let y_scale = match axis {
+                _ if self.normalized => {
+                    let (ymin, ymax) = self.series_bounds(shape, samples, envelope);
+                    Scale::new(ymin..ymax, 0.0..self.height as f32)
+                }
+                YAxis::Primary => self.y_scale(),
+                YAxis::Secondary => Scale::new(self.y2min..self.y2max, 0.0..self.height as f32),
+            };
+
+            // translate (x, y) points into screen coordinates
+            let points: Vec<_> = match shape {
+                Shape::Continuous(_) | Shape::ParallelContinuous(_) => (0..self.width)
+                    .zip(samples)
+                    .filter_map(|(i, &y)| {
+                        if y.is_finite() {
+                            let j = y_scale.linear(y).round();
+                            Some((i, self.height - j as u32))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+                // lines are clipped at the viewport boundary separately,
+                // and colored points, bubbles and labeled points are drawn along with their
+                // colors, sizes and labels
+                Shape::Lines(_)
+                | Shape::Bezier(_)
+                | Shape::Polygon(_)
+                | Shape::Gapped(_)
+                | Shape::ColoredPoints(_)
+                | Shape::Bubbles(_)
+                | Shape::LabeledPoints(_)
+                | Shape::Circle { .. }
+                | Shape::Ellipse { .. } => Vec::new(),
+                Shape::Points(dt) | Shape::Steps(dt) | Shape::Bars(dt) => dt
+                    .iter()
+                    .filter_map(|(x, y)| {
+                        let i = x_scale.linear(*x).round() as u32;
+                        let j = y_scale.linear(*y).round() as u32;
                         if i <= self.width && j <= self.height {
                             Some((i, self.height - j))
                         } else {
@@ -493,10 +2988,135 @@ impl<'a> Chart<'a> {
                     .collect(),
             };
 
+            let points = match shape {
+                // duplicates are counted in density mode
+                Shape::Points(_)
+                    if self.downsample && !density && points.len() > self.width as usize =>
+                {
+                    dedup_pixels(points, self.width, self.height)
+                }
+                _ => points,
+            };
+
+            // break continuous shapes at jumps, like the vertical asymptotes of tan(x)
+            let breaks: Vec<bool> = match shape.function() {
+                Some(f) => points
+                    .windows(2)
+                    .map(|pair| {
+                        let (i1, j1) = pair[0];
+                        let (i2, j2) = pair[1];
+                        let jump = (j1 as f32 - j2 as f32).abs();
+                        jump > self.jump_threshold * self.height as f32
+                            && is_discontinuous(
+                                f,
+                                (x_scale.inv_linear(i1 as f32), samples[i1 as usize]),
+                                (x_scale.inv_linear(i2 as f32), samples[i2 as usize]),
+                            )
+                    })
+                    .collect(),
+                None => Vec::new(),
+            };
+
             // display segments
             match shape {
-                Shape::Continuous(_) | Shape::Lines(_) => {
-                    for pair in points.windows(2) {
+                Shape::Points(dt) | Shape::Lines(dt) if self.envelope => {
+                    let mut columns =
+                        vec![(f32::INFINITY, f32::NEG_INFINITY); self.width as usize + 1];
+                    for &(x, y) in dt.iter() {
+                        if x >= self.xmin && x <= self.xmax && y.is_finite() {
+                            let (lo, hi) = &mut columns[x_scale.linear(x).round() as usize];
+                            (*lo, *hi) = (lo.min(y), hi.max(y));
+                        }
+                    }
+                    let height = self.height;
+                    draw_envelope(&mut self.canvas, &columns, &y_scale, height, color);
+                }
+                Shape::Lines(_) | Shape::Bezier(_) | Shape::Polygon(_) | Shape::Gapped(_) => {
+                    let to_pixel = |(x, y): (f32, f32)| {
+                        let i = x_scale.linear_unclamped(x);
+                        let j = y_scale.linear_unclamped(y);
+                        // a zero-height range maps everything to the bottom, as `linear` does
+                        let j = if j.is_nan() { y_scale.linear(y) } else { j };
+                        (i, self.height as f32 - j)
+                    };
+                    let pixels: Vec<(f32, f32)> = match shape {
+                        Shape::Lines(dt) | Shape::Bezier(dt) | Shape::Polygon(dt) => {
+                            dt.iter().copied().map(to_pixel).collect()
+                        }
+                        // missing values break the line, as points that aren't finite do
+                        Shape::Gapped(dt) => dt
+                            .iter()
+                            .map(|&(x, y)| match y {
+                                Some(y) => to_pixel((x, y)),
+                                None => (f32::NAN, f32::NAN),
+                            })
+                            .collect(),
+                        _ => unreachable!(),
+                    };
+
+                    if let (Shape::Gapped(dt), true) = (shape, self.shade_gaps) {
+                        for (x1, x2) in gaps(dt) {
+                            let from = x1.map_or(0, |x| x_scale.linear(x).floor() as u32 + 1);
+                            let to = x2.map_or(self.width, |x| {
+                                (x_scale.linear(x).ceil() as u32).saturating_sub(1)
+                            });
+                            hatch(&mut self.canvas, from..=to, 0..=self.height, color);
+                        }
+                    }
+
+                    let pixels = match shape {
+                        // about a point for every point of the control polygon
+                        Shape::Bezier(_) => {
+                            let pieces = pixels.windows(2).map(|p| subdivisions(p[0], p[1])).sum();
+                            bezier(&pixels, pieces)
+                        }
+                        Shape::Polygon(_) => {
+                            if self.polygon_fill {
+                                for (y, x1, x2) in scanline_fill(&pixels, self.width, self.height) {
+                                    for x in x1..=x2 {
+                                        if let Some(color) = color {
+                                            let color = rgb_to_pixelcolor(color);
+                                            self.canvas.set_colored(x, y, color);
+                                        } else {
+                                            self.canvas.set(x, y);
+                                        }
+                                    }
+                                }
+                            }
+                            // close the outline
+                            pixels.iter().chain(pixels.first()).copied().collect()
+                        }
+                        _ => interpolate(&pixels, self.interpolation),
+                    };
+                    for polyline in clip_polyline(&pixels, self.width, self.height) {
+                        let polyline = if self.downsample && polyline.len() > self.width as usize {
+                            downsample_columns(polyline)
+                        } else {
+                            polyline
+                        };
+
+                        for pair in polyline.windows(2) {
+                            let (x1, y1) = pair[0];
+                            let (x2, y2) = pair[1];
+                            if let Some(color) = color {
+                                let color = rgb_to_pixelcolor(color);
+                                self.canvas.line_colored(x1, y1, x2, y2, color);
+                            } else {
+                                self.canvas.line(x1, y1, x2, y2);
+                            }
+                        }
+                    }
+                }
+                Shape::Continuous(_) | Shape::ParallelContinuous(_) if !envelope.is_empty() => {
+                    let height = self.height;
+                    draw_envelope(&mut self.canvas, envelope, &y_scale, height, color);
+                }
+                Shape::Continuous(_) | Shape::ParallelContinuous(_) => {
+                    for (k, pair) in points.windows(2).enumerate() {
+                        if breaks.get(k) == Some(&true) {
+                            continue;
+                        }
+
                         let (x1, y1) = pair[0];
                         let (x2, y2) = pair[1];
                         if let Some(color) = color {
@@ -507,6 +3127,110 @@ impl<'a> Chart<'a> {
                         }
                     }
                 }
+                Shape::ColoredPoints(dt) => {
+                    for &(x, y, point_color) in dt.iter() {
+                        let i = x_scale.linear(x).round() as u32;
+                        let j = y_scale.linear(y).round() as u32;
+                        if i <= self.width && j <= self.height {
+                            let color = rgb_to_pixelcolor(&point_color);
+                            self.canvas.set_colored(i, self.height - j, color);
+                        }
+                    }
+                }
+                Shape::Circle { .. } | Shape::Ellipse { .. } => {
+                    let Some(((x, y), (rx, ry))) = shape.ellipse() else {
+                        continue;
+                    };
+                    let center = (
+                        x_scale.linear_unclamped(x).round() as f64,
+                        self.height as f64 - y_scale.linear_unclamped(y).round() as f64,
+                    );
+                    let radii = (
+                        (x_scale.linear_unclamped(x + rx.abs()) - x_scale.linear_unclamped(x))
+                            .round() as f64,
+                        (y_scale.linear_unclamped(y + ry.abs()) - y_scale.linear_unclamped(y))
+                            .round() as f64,
+                    );
+
+                    for (x, y) in ellipse_outline(center, radii, self.width, self.height) {
+                        if let Some(color) = color {
+                            let color = rgb_to_pixelcolor(color);
+                            self.canvas.set_colored(x, y, color);
+                        } else {
+                            self.canvas.set(x, y);
+                        }
+                    }
+                }
+                Shape::LabeledPoints(dt) => {
+                    for &(x, y, label) in dt.iter() {
+                        let i = x_scale.linear(x).round() as u32;
+                        let j = y_scale.linear(y).round() as u32;
+                        if x < self.xmin || x > self.xmax || i > self.width || j > self.height {
+                            continue;
+                        }
+                        let j = self.height - j;
+                        if let Some(color) = color {
+                            self.canvas.set_colored(i, j, rgb_to_pixelcolor(color));
+                        } else {
+                            self.canvas.set(i, j);
+                        }
+                        labels.push((i, j, label));
+                    }
+                }
+                Shape::Bubbles(dt) => {
+                    const MAX_BUBBLE_RADIUS: i64 = 3;
+
+                    let largest = dt
+                        .iter()
+                        .map(|(_, _, size)| *size)
+                        .filter(|size| size.is_finite())
+                        .fold(0.0, f32::max);
+
+                    for &(x, y, size) in dt.iter() {
+                        let i = x_scale.linear(x).round() as i64;
+                        let j = self.height as i64 - y_scale.linear(y).round() as i64;
+                        let radius = if largest > 0.0 && size > 0.0 {
+                            (size / largest * MAX_BUBBLE_RADIUS as f32).round() as i64
+                        } else {
+                            0
+                        };
+
+                        for dj in -radius..=radius {
+                            for di in -radius..=radius {
+                                let (x, y) = (i + di, j + dj);
+                                if di * di + dj * dj > radius * radius + radius / 2
+                                    || x < 0
+                                    || y < 0
+                                    || x > self.width as i64
+                                    || y > self.height as i64
+                                {
+                                    continue;
+                                }
+                                let (x, y) = (x as u32, y as u32);
+                                if let Some(color) = color {
+                                    let color = rgb_to_pixelcolor(color);
+                                    self.canvas.set_colored(x, y, color);
+                                } else {
+                                    self.canvas.set(x, y);
+                                }
+                            }
+                        }
+                    }
+                }
+                Shape::Points(_) if *density => {
+                    // brighter towards white with every point landing in the same cell
+                    let ramp =
+                        color.map(|color| Colormap::new(vec![color, RGB8::new(255, 255, 255)]));
+                    for (x, y, hits) in density_dots(&points, self.width, self.height) {
+                        match &ramp {
+                            Some(ramp) => {
+                                let color = ramp.map((hits - 1) as f32 / 14.0);
+                                self.canvas.set_colored(x, y, rgb_to_pixelcolor(&color));
+                            }
+                            None => self.canvas.set(x, y),
+                        }
+                    }
+                }
                 Shape::Points(_) => {
                     for (x, y) in points {
                         if let Some(color) = color {
@@ -518,21 +3242,49 @@ impl<'a> Chart<'a> {
                     }
                 }
                 Shape::Steps(_) => {
+                    let mut segments = Vec::new();
                     for pair in points.windows(2) {
                         let (x1, y1) = pair[0];
                         let (x2, y2) = pair[1];
 
+                        match self.step_style {
+                            StepStyle::Pre => {
+                                segments.push((x1, y2, x2, y2));
+                                segments.push((x1, y1, x1, y2));
+                            }
+                            StepStyle::Post => {
+                                segments.push((x1, y1, x2, y1));
+                                segments.push((x2, y1, x2, y2));
+                            }
+                            StepStyle::Mid => {
+                                let xm = (x1 + x2) / 2;
+                                segments.push((x1, y1, xm, y1));
+                                segments.push((xm, y1, xm, y2));
+                                segments.push((xm, y2, x2, y2));
+                            }
+                        }
+                    }
+
+                    // the last value holds until the end of the chart
+                    if let (StepStyle::Post, Some(&(x, y))) = (self.step_style, points.last()) {
+                        segments.push((x, y, self.width, y));
+                    }
+
+                    for (x1, y1, x2, y2) in segments {
                         if let Some(color) = color {
                             let color = rgb_to_pixelcolor(color);
-                            self.canvas.line_colored(x1, y2, x2, y2, color);
-                            self.canvas.line_colored(x1, y1, x1, y2, color);
+                            self.canvas.line_colored(x1, y1, x2, y2, color);
                         } else {
-                            self.canvas.line(x1, y2, x2, y2);
-                            self.canvas.line(x1, y1, x1, y2);
+                            self.canvas.line(x1, y1, x2, y2);
                         }
                     }
                 }
                 Shape::Bars(_) => {
+                    let base = match self.baseline {
+                        Some(y) => self.height - y_scale.linear(y).round() as u32,
+                        None => self.height,
+                    };
+
                     for pair in points.windows(2) {
                         let (x1, y1) = pair[0];
                         let (x2, y2) = pair[1];
@@ -541,145 +3293,1943 @@ impl<'a> Chart<'a> {
                             let color = rgb_to_pixelcolor(color);
                             self.canvas.line_colored(x1, y2, x2, y2, color);
                             self.canvas.line_colored(x1, y1, x1, y2, color);
-                            self.canvas.line_colored(x1, self.height, x1, y1, color);
-                            self.canvas.line_colored(x2, self.height, x2, y2, color);
+                            self.canvas.line_colored(x1, base, x1, y1, color);
+                            self.canvas.line_colored(x2, base, x2, y2, color);
                         } else {
                             self.canvas.line(x1, y2, x2, y2);
                             self.canvas.line(x1, y1, x1, y2);
-                            self.canvas.line(x1, self.height, x1, y1);
-                            self.canvas.line(x2, self.height, x2, y2);
+                            self.canvas.line(x1, base, x1, y1);
+                            self.canvas.line(x2, base, x2, y2);
                         }
                     }
                 }
             }
         }
+
+        if let Some((canvas, color)) = layer {
+            let layer = mem::replace(&mut self.canvas, canvas);
+            let (mixing, size) = (self.color_mixing, (self.width, self.height));
+            merge_layer(&mut self.canvas, &layer, size, color, mixing, &mut cells);
+        }
+        place_labels(&mut self.canvas, &labels, self.width, self.height);
+
+        for (drawable, color) in self.drawables.clone() {
+            drawable.draw(&mut DrawContext { chart: self, color });
+        }
     }
 
-    /// Returns the frame.
-    pub fn frame(&self) -> String {
-        self.canvas.frame()
+    /// Removes all shapes and drawings from the chart, keeping its configuration and the
+    /// allocated buffers, so that the chart can be re-plotted without being rebuilt.
+    /// Automatically ranged axes are re-ranged by the shapes plotted afterwards.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let first = Shape::Lines(&[(0.0, 0.0), (1.0, 1.0)]);
+    /// let second = Shape::Lines(&[(0.0, 5.0), (1.0, 2.0)]);
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 1.0);
+    /// let chart = chart.lineplot(&first);
+    /// chart.display();
+    ///
+    /// chart.clear();
+    /// let chart = chart.lineplot(&second);
+    /// assert_eq!((2.0, 5.0), chart.y_range());
+    /// chart.display();
+    /// ```
+    pub fn clear(&mut self) {
+        self.canvas.clear();
+        self.shapes.clear();
+        self.spans.clear();
+        self.drawables.clear();
+        self.series.clear();
+
+        self.data_y_range = (f32::INFINITY, f32::NEG_INFINITY);
+        self.data_y2_range = (f32::INFINITY, f32::NEG_INFINITY);
+        self.pad_y_ranges();
     }
 
-    fn rescale(&mut self, shape: &Shape) {
-        // rescale ymin and ymax
-        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+    /// Rebuilds the canvas at a new size, keeping the ranges, shapes, styles and labels,
+    /// so that the chart can follow the size of the terminal. Continuous shapes are sampled
+    /// again at the new width, while lines and points drawn directly with `draw_line` and
+    /// friends are cleared along with the old canvas.
+    ///
+    /// The size is raised to the minimum like with `new`.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let shape = Shape::Continuous(Box::new(|x| x.cos()));
+    /// let mut chart = Chart::new(120, 60, -5.0, 5.0);
+    /// let chart = chart.lineplot(&shape);
+    /// chart.display();
+    ///
+    /// chart.resize(60, 20);
+    /// assert_eq!(31, chart.render().lines().next().unwrap().chars().count() - " 1.0".len());
+    /// ```
+    pub fn resize(&mut self, width: u32, height: u32) {
+        let (width, height, clamped) = clamp_size(width, height);
 
-        let ys: Vec<_> = match shape {
-            Shape::Continuous(f) => (0..self.width)
-                .filter_map(|i| {
-                    let x = x_scale.inv_linear(i as f32);
-                    let y = f(x);
-                    if y.is_normal() {
-                        Some(y)
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-            Shape::Points(dt) | Shape::Lines(dt) | Shape::Steps(dt) | Shape::Bars(dt) => dt
-                .iter()
-                .filter_map(|(x, y)| {
-                    if *x >= self.xmin && *x <= self.xmax {
-                        Some(*y)
-                    } else {
-                        None
-                    }
-                })
-                .collect(),
-        };
+        self.width = width;
+        self.height = height;
+        self.clamped = clamped;
+        self.canvas = BrailleCanvas::new(width, height);
 
-        let ymax = *ys
-            .iter()
-            .max_by(|x, y| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal))
-            .unwrap_or(&0.0);
-        let ymin = *ys
-            .iter()
-            .min_by(|x, y| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal))
-            .unwrap_or(&0.0);
+        // continuous shapes are sampled per column, so their bounds may change as well
+        self.resample();
+    }
+
+    /// Samples the continuous shapes again and recalculates the data ranges.
+    fn resample(&mut self) {
+        self.data_y_range = (f32::INFINITY, f32::NEG_INFINITY);
+        self.data_y2_range = (f32::INFINITY, f32::NEG_INFINITY);
+        for k in 0..self.shapes.len() {
+            let (samples, envelope, bounds) = {
+                let shape = self.shapes[k].shape.get();
+                let (samples, envelope) = self.sample(&shape);
+                let bounds = self.series_bounds(&shape, &samples, &envelope);
+                (samples, envelope, bounds)
+            };
+            self.shapes[k].samples = samples;
+            self.shapes[k].envelope = envelope;
+            self.extend_axis_range(self.shapes[k].axis, bounds);
+        }
+        for k in 0..self.drawables.len() {
+            if let Some(bounds) = self.drawables[k].0.y_bounds() {
+                self.extend_y_range(bounds);
+            }
+        }
+        self.pad_y_ranges();
+    }
 
-        self.ymin = f32::min(self.ymin, ymin);
-        self.ymax = f32::max(self.ymax, ymax);
+    /// Same as `to_string`, but without any color escape sequences, whatever the colors are
+    /// configured to, which makes the output deterministic for snapshot tests and logs.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, ColorPlot, Shape};
+    ///
+    /// colored::control::set_override(true);
+    ///
+    /// let shape = Shape::Continuous(Box::new(|x| x.cos()));
+    /// let mut chart = Chart::new(120, 60, -5.0, 5.0);
+    /// let chart = chart.linecolorplot(&shape, rgb::RGB8::new(255, 0, 0));
+    ///
+    /// assert!(chart.render().contains('\u{1b}'));
+    /// assert!(!chart.to_plain_string().contains('\u{1b}'));
+    /// ```
+    pub fn to_plain_string(&self) -> String {
+        strip_escapes(&self.to_string()).collect()
     }
-}
 
-impl<'a> ColorPlot<'a> for Chart<'a> {
-    fn linecolorplot(&'a mut self, shape: &'a Shape, color: RGB8) -> &'a mut Chart {
-        self.shapes.push((shape, Some(color)));
-        if self.y_ranging == ChartRangeMethod::AutoRange {
-            self.rescale(shape);
+    /// Makes the chart follow the x-axis shared with other charts, taking its range and its
+    /// label format, if it has one, see [`SharedXAxis`].
+    pub fn share_x_axis(&mut self, axis: &SharedXAxis) -> &mut Self {
+        self.shared_x = Some(axis.clone());
+        let (xmin, xmax) = axis.range();
+        if (xmin, xmax) != (self.xmin, self.xmax) {
+            self.set_x_range(xmin, xmax);
         }
         self
     }
-}
 
-impl<'a> Plot<'a> for Chart<'a> {
-    fn lineplot(&'a mut self, shape: &'a Shape) -> &'a mut Chart {
-        self.shapes.push((shape, None));
-        if self.y_ranging == ChartRangeMethod::AutoRange {
-            self.rescale(shape);
+    /// Calls `f` with the format of the x-axis labels, the one of the shared x-axis if any.
+    fn format_x<R>(&self, f: impl FnOnce(&LabelFormat) -> R) -> R {
+        match self.shared_x.as_ref().and_then(SharedXAxis::label_format) {
+            Some(format) => f(&format),
+            None => f(&self.x_label_format),
         }
-        self
     }
-}
 
-fn rgb_to_pixelcolor(rgb: &RGB8) -> PixelColor {
-    PixelColor::TrueColor {
+    /// Makes `render` and `display` call `report` with the diagnostics whenever some points
+    /// can't be drawn as they are, see `diagnostics`, instead of leaving them out silently.
+    ///
+    /// ```rust
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let readings = [(0.0, 1.0), (1.0, f32::NAN), (2.0, 3.0)];
+    /// let shape = Shape::Points(&readings);
+    /// let dropped = Rc::new(Cell::new(0));
+    ///
+    /// let report = Rc::clone(&dropped);
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .lineplot(&shape)
+    ///     .strict(move |diagnostics| report.set(diagnostics.nan))
+    ///     .render();
+    ///
+    /// assert_eq!(1, dropped.get());
+    /// ```
+    pub fn strict(&mut self, report: impl FnMut(&Diagnostics) + 'static) -> &mut Self {
+        self.strict = Some(Box::new(report));
+        self
+    }
+
+    /// Counts the plotted points that can't be drawn as they are, for finding out why
+    /// a chart looks empty or is missing parts.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let readings = [(0.0, 1.0), (1.0, f32::NAN), (2.0, 3.0), (15.0, 2.0)];
+    /// let shape = Shape::Points(&readings);
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 10.0);
+    /// let chart = chart.lineplot(&shape);
+    ///
+    /// let diagnostics = chart.diagnostics();
+    /// assert_eq!(1, diagnostics.nan);
+    /// assert_eq!(1, diagnostics.out_of_range);
+    ///
+    /// // zeros are drawn like any other value
+    /// let ramp = Shape::Continuous(Box::new(|x: f32| x.max(0.0)));
+    /// let mut chart = Chart::new(60, 12, -1.0, 1.0);
+    /// assert!(chart.lineplot(&ramp).diagnostics().is_clean());
+    /// ```
+    pub fn diagnostics(&self) -> Diagnostics {
+        let mut diagnostics = Diagnostics::default();
+
+        for series in &self.shapes {
+            let (ymin, ymax) = match series.axis {
+                _ if self.normalized => (f32::NEG_INFINITY, f32::INFINITY),
+                YAxis::Primary => (self.ymin, self.ymax),
+                YAxis::Secondary => (self.y2min, self.y2max),
+            };
+
+            let shape = series.shape.get();
+            if shape.function().is_some() {
+                for y in &series.samples {
+                    if y.is_nan() {
+                        diagnostics.nan += 1;
+                    } else if y.is_infinite() {
+                        diagnostics.non_normal += 1;
+                    } else if *y < ymin || *y > ymax {
+                        diagnostics.out_of_range += 1;
+                    }
+                }
+                continue;
+            }
+
+            for (x, y) in data_points(&shape) {
+                if x.is_nan() || y.is_nan() {
+                    diagnostics.nan += 1;
+                } else if x.is_infinite() || y.is_infinite() {
+                    diagnostics.non_normal += 1;
+                } else if x < self.xmin || x > self.xmax || y < ymin || y > ymax {
+                    diagnostics.out_of_range += 1;
+                }
+            }
+        }
+        diagnostics.degenerate = self.is_degenerate();
+
+        diagnostics
+    }
+
+    /// Returns whether the chart has nothing to draw in: no finite data was plotted on an
+    /// auto-ranged primary axis, or the x or the y range has no width.
+    ///
+    /// Charts of empty or all-NaN series still render, with the y range falling back to `0..1`.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let readings = [(0.0, f32::NAN), (1.0, f32::NAN)];
+    /// let shape = Shape::Lines(&readings);
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 1.0);
+    /// let chart = chart.lineplot(&shape);
+    ///
+    /// assert!(chart.is_degenerate());
+    /// assert_eq!((0.0, 1.0), chart.y_range());
+    /// ```
+    pub fn is_degenerate(&self) -> bool {
+        let empty = |(min, max): (f32, f32)| min.partial_cmp(&max) != Some(cmp::Ordering::Less);
+
+        let no_data = self.y_ranging == ChartRangeMethod::AutoRange
+            && self.data_y_range.0 > self.data_y_range.1;
+
+        no_data || empty((self.xmin, self.xmax)) || empty((self.ymin, self.ymax))
+    }
+
+    /// Returns the descriptions of the plotted series, in the order they were plotted.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, ColorPlot, Plot, SeriesBuilder, Shape};
+    ///
+    /// let cpu = Shape::Lines(&[(0.0, 10.0), (1.0, 35.0), (2.0, 20.0)]);
+    /// let limit = Shape::Continuous(Box::new(|_| 80.0));
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 2.0);
+    /// let chart = chart
+    ///     .linecolorplot(&cpu, rgb::RGB8::new(0, 255, 0))
+    ///     .series_name("cpu")
+    ///     .lineplot(&limit);
+    ///
+    /// let series = chart.series();
+    /// assert_eq!(Some("cpu"), series[0].name.as_deref());
+    /// assert_eq!(3, series[0].points);
+    /// assert_eq!(None, series[1].color);
+    /// ```
+    pub fn series(&self) -> &[SeriesInfo] {
+        &self.series
+    }
+
+    /// Returns the value of every series nearest to the given canvas column, along with the
+    /// index of the series (in plotting order, as returned by `series`). Series without a
+    /// finite value there are left out. Useful for implementing hover or inspection on top of the chart.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let line = Shape::Lines(&[(0.0, 1.0), (5.0, 2.0), (10.0, 3.0)]);
+    /// let parabola = Shape::Continuous(Box::new(|x| x * x));
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 10.0);
+    /// let chart = chart.lineplot(&line).lineplot(&parabola);
+    ///
+    /// assert_eq!(vec![(0, 2.0), (1, 25.0)], chart.value_at_column(60));
+    /// ```
+    pub fn value_at_column(&self, column: u32) -> Vec<(usize, f32)> {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let x = x_scale.inv_linear(column as f32);
+
+        self.shapes
+            .iter()
+            .enumerate()
+            .filter_map(|(k, series)| {
+                let shape = series.shape.get();
+                let y = match shape.function() {
+                    Some(_) => series.samples.get(column as usize).copied(),
+                    None => self
+                        .drawn_points(&shape)
+                        .filter(|(_, y)| y.is_finite())
+                        .min_by(|a, b| {
+                            let (da, db) = ((a.0 - x).abs(), (b.0 - x).abs());
+                            da.partial_cmp(&db).unwrap_or(cmp::Ordering::Equal)
+                        })
+                        .map(|(_, y)| y),
+                };
+                y.filter(|y| y.is_finite()).map(|y| (k, y))
+            })
+            .collect()
+    }
+
+    /// Moves the series with the given index (in plotting order, as returned by `series`)
+    /// over all the others in the drawing order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no series with the given index.
+    pub fn bring_to_front(&mut self, index: usize) {
+        let top = self.shapes.iter().map(|series| series.z).max().unwrap_or(0);
+        if self.shapes.iter().filter(|series| series.z == top).count() > 1
+            || self.shapes[index].z != top
+        {
+            self.shapes[index].z = top + 1;
+        }
+    }
+
+    /// Returns whether the size the chart was created or resized with was smaller than
+    /// [`MIN_WIDTH`] by [`MIN_HEIGHT`], and was raised to it.
+    ///
+    /// ```rust
+    /// use textplots::Chart;
+    ///
+    /// assert!(Chart::new(20, 60, 0.0, 1.0).is_clamped());
+    /// assert!(!Chart::new(120, 60, 0.0, 1.0).is_clamped());
+    /// ```
+    pub fn is_clamped(&self) -> bool {
+        self.clamped
+    }
+
+    /// Returns the x-axis range as `(xmin, xmax)`.
+    pub fn x_range(&self) -> (f32, f32) {
+        (self.xmin, self.xmax)
+    }
+
+    /// Changes the x-axis range, for panning and zooming. Continuous shapes are sampled again,
+    /// and automatically ranged y-axes follow the data within the new range. Lines and points
+    /// drawn directly with `draw_line` and friends are cleared, as with `resize`.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let readings = [(0.0, 1.0), (5.0, 2.0), (10.0, 8.0)];
+    /// let shape = Shape::Lines(&readings);
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 10.0);
+    /// let chart = chart.lineplot(&shape);
+    ///
+    /// chart.set_x_range(0.0, 5.0);
+    /// assert_eq!((1.0, 2.0), chart.y_range());
+    /// ```
+    pub fn set_x_range(&mut self, xmin: f32, xmax: f32) {
+        self.xmin = xmin;
+        self.xmax = xmax;
+        self.canvas.clear();
+        self.resample();
+    }
+
+    /// Returns the y-axis range as `(ymin, ymax)`, as calculated from the plotted shapes
+    /// (including padding) unless the range is fixed.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let mut chart = Chart::new(120, 60, 0.0, 2.0);
+    /// let chart = chart.lineplot(&Shape::Lines(&[(0.0, 1.0), (2.0, 5.0)]));
+    ///
+    /// assert_eq!((1.0, 5.0), chart.y_range());
+    /// ```
+    pub fn y_range(&self) -> (f32, f32) {
+        (self.ymin, self.ymax)
+    }
+
+    /// Translates a point in data coordinates into canvas pixel coordinates, with the origin
+    /// in the top left corner. Points outside of the chart ranges are clamped to the edges.
+    ///
+    /// ```rust
+    /// use textplots::Chart;
+    ///
+    /// let chart = Chart::new_with_y_range(120, 60, 0.0, 10.0, 0.0, 1.0);
+    ///
+    /// assert_eq!((60, 60), chart.data_to_pixel(5.0, 0.0));
+    /// assert_eq!((5.0, 0.5), chart.pixel_to_data(60, 30));
+    /// ```
+    pub fn data_to_pixel(&self, x: f32, y: f32) -> (u32, u32) {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let y_scale = self.y_scale();
+
+        let i = x_scale.linear(x).round() as u32;
+        let j = y_scale.linear(y).round() as u32;
+        (i, self.height - j)
+    }
+
+    /// Translates canvas pixel coordinates (origin in the top left corner) into data coordinates.
+    pub fn pixel_to_data(&self, i: u32, j: u32) -> (f32, f32) {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let y_scale = self.y_scale();
+
+        let x = x_scale.inv_linear(i as f32);
+        let y = y_scale.inv_linear(self.height as f32 - j as f32);
+        (x, y)
+    }
+
+    /// Draws a line between two points given in data coordinates, clipped to the chart area.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sin())))
+    ///     .draw_rect((2.0, -0.5), (4.0, 0.5), None)
+    ///     .draw_line((0.0, 0.0), (10.0, 1.0), None)
+    ///     .draw_point((5.0, 0.0), None)
+    ///     .display();
+    /// ```
+    pub fn draw_line(&mut self, p1: (f32, f32), p2: (f32, f32), color: Option<RGB8>) -> &mut Self {
+        let clipped = clip_segment(
+            self.to_canvas(p1),
+            self.to_canvas(p2),
+            self.width as f32,
+            self.height as f32,
+        );
+
+        if let Some(((x1, y1), (x2, y2))) = clipped {
+            let (x1, y1) = (x1.round() as u32, y1.round() as u32);
+            let (x2, y2) = (x2.round() as u32, y2.round() as u32);
+            match color {
+                Some(color) => self
+                    .canvas
+                    .line_colored(x1, y1, x2, y2, rgb_to_pixelcolor(&color)),
+                None => self.canvas.line(x1, y1, x2, y2),
+            }
+        }
+        self
+    }
+
+    /// Draws a single point given in data coordinates, unless it lies outside of the chart area.
+    pub fn draw_point(&mut self, p: (f32, f32), color: Option<RGB8>) -> &mut Self {
+        let (x, y) = self.to_canvas(p);
+        if (0.0..=self.width as f32).contains(&x) && (0.0..=self.height as f32).contains(&y) {
+            self.dot(x.round() as u32, y.round() as u32, color);
+        }
+        self
+    }
+
+    /// Draws the outline of a rectangle spanned by two opposite corners given in data coordinates.
+    pub fn draw_rect(&mut self, p1: (f32, f32), p2: (f32, f32), color: Option<RGB8>) -> &mut Self {
+        let (x1, y1) = p1;
+        let (x2, y2) = p2;
+        self.draw_line((x1, y1), (x2, y1), color)
+            .draw_line((x2, y1), (x2, y2), color)
+            .draw_line((x2, y2), (x1, y2), color)
+            .draw_line((x1, y2), (x1, y1), color)
+    }
+
+    /// Shades the band of the chart between two y values behind the shapes, like an alert
+    /// threshold or a target range. The y range isn't extended to cover the band.
+    ///
+    /// ```rust
+    /// use rgb::RGB8;
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// let latency = Shape::Continuous(Box::new(|x| 100.0 + 40.0 * (x / 2.0).sin()));
+    ///
+    /// Chart::new(120, 60, 0.0, 24.0)
+    ///     .lineplot(&latency)
+    ///     .axhspan(120.0, 140.0, Some(RGB8::new(255, 0, 0)))
+    ///     .axvspan(2.0, 4.5, None)
+    ///     .display();
+    /// ```
+    pub fn axhspan(&mut self, y0: f32, y1: f32, color: Option<RGB8>) -> &mut Self {
+        self.spans.push((Span::Horizontal(y0..y1), color));
+        self
+    }
+
+    /// Shades the band of the chart between two x values behind the shapes, like a
+    /// maintenance window, see `axhspan`.
+    pub fn axvspan(&mut self, x0: f32, x1: f32, color: Option<RGB8>) -> &mut Self {
+        self.spans.push((Span::Vertical(x0..x1), color));
+        self
+    }
+
+    /// Draws a line between two points given in data coordinates with an arrowhead at `to`,
+    /// for pointing at features of the data.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape};
+    ///
+    /// Chart::new(120, 60, 0.0, 10.0)
+    ///     .lineplot(&Shape::Continuous(Box::new(|x| x.sin())))
+    ///     .arrow((6.0, -0.2), (7.85, 0.95), Some(rgb::RGB8::new(255, 0, 0)))
+    ///     .display();
+    /// ```
+    pub fn arrow(&mut self, from: (f32, f32), to: (f32, f32), color: Option<RGB8>) -> &mut Self {
+        // length of the arrowhead sides in canvas points, and their angle to the shaft
+        const HEAD_LENGTH: f32 = 4.0;
+        const HEAD_ANGLE: f32 = 0.5;
+
+        self.draw_line(from, to, color);
+
+        let (x1, y1) = self.to_canvas(from);
+        let (x2, y2) = self.to_canvas(to);
+        let direction = (y1 - y2).atan2(x1 - x2);
+        if !direction.is_finite() || (x1 == x2 && y1 == y2) {
+            return self;
+        }
+
+        for angle in [direction - HEAD_ANGLE, direction + HEAD_ANGLE] {
+            let side = (
+                x2 + HEAD_LENGTH * angle.cos(),
+                y2 + HEAD_LENGTH * angle.sin(),
+            );
+            let clipped = clip_segment((x2, y2), side, self.width as f32, self.height as f32);
+            if let Some(((x1, y1), (x2, y2))) = clipped {
+                let (x1, y1) = (x1.round() as u32, y1.round() as u32);
+                let (x2, y2) = (x2.round() as u32, y2.round() as u32);
+                match color {
+                    Some(color) => {
+                        self.canvas
+                            .line_colored(x1, y1, x2, y2, rgb_to_pixelcolor(&color))
+                    }
+                    None => self.canvas.line(x1, y1, x2, y2),
+                }
+            }
+        }
+        self
+    }
+
+    /// Translates a point in data coordinates into unclamped canvas coordinates.
+    fn to_canvas(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let y_scale = self.y_scale();
+
+        let i = x_scale.linear_unclamped(x);
+        let j = y_scale.linear_unclamped(y);
+        (i, self.height as f32 - j)
+    }
+
+    /// Returns the frame.
+    pub fn frame(&self) -> String {
+        self.canvas.frame()
+    }
+
+    /// Returns the number of text rows the rendered chart takes, labels, titles and legends
+    /// included, for laying it out next to other output before rendering it. The layout doesn't
+    /// depend on what is drawn, so it is known as soon as the chart is configured.
+    ///
+    /// ```rust
+    /// use textplots::{Chart, Plot, Shape, TextBuilder};
+    ///
+    /// let shape = Shape::Continuous(Box::new(|x| x.sin()));
+    /// let mut chart = Chart::new(120, 60, -5.0, 5.0);
+    /// let chart = chart.lineplot(&shape).x_title("time");
+    ///
+    /// let (rows, columns) = (chart.text_rows(), chart.text_columns());
+    /// let rendered = chart.render();
+    /// assert_eq!(rows, rendered.lines().count());
+    /// assert_eq!(columns, rendered.lines().map(|line| line.chars().count()).max().unwrap());
+    /// ```
+    pub fn text_rows(&self) -> usize {
+        let (rows, columns) = self.canvas_cells();
+        if rows < 2 {
+            return rows;
+        }
+
+        let boxed = self.border_style == Some(BorderStyle::Box);
+        let text_width = self.gutter_width() + columns + if boxed { 2 } else { 0 };
+        let wrapped =
+            |text: &Option<String>| text.as_ref().map_or(0, |t| wrap(t, text_width).len());
+
+        wrapped(&self.caption)
+            + usize::from(self.y_title.is_some())
+            + if boxed { 2 } else { 0 }
+            + rows
+            + usize::from(!self.hide_x_labels)
+            + usize::from(self.x_title.is_some())
+            + usize::from(self.legend)
+            + if self.stats { self.shapes.len() } else { 0 }
+            + wrapped(&self.footer)
+    }
+
+    /// Returns the number of text columns the widest row of the rendered chart takes,
+    /// see `text_rows`.
+    pub fn text_columns(&self) -> usize {
+        let (rows, columns) = self.canvas_cells();
+        if rows < 2 {
+            return columns;
+        }
+
+        let boxed = self.border_style == Some(BorderStyle::Box);
+        let indent = self.gutter_width();
+        let text_width = indent + columns + if boxed { 2 } else { 0 };
+        let mut widths = vec![text_width];
+
+        // right of the canvas rows, the same way as `fmt` lays them out
+        let (_, right_labels, _) = self.y_label_sides(rows);
+        let colorbar = self.colorbar_cells(rows);
+        let end_values = self.end_value_cells(rows);
+        let end_width = end_values.iter().map(|v| label_width(v)).max().unwrap_or(0);
+        let right_width = right_labels
+            .iter()
+            .map(|l| label_width(l))
+            .max()
+            .unwrap_or(0);
+        for ((right_label, bar), end_value) in right_labels.iter().zip(&colorbar).zip(&end_values) {
+            let mut width = text_width;
+            if end_width > 0 && (!right_label.is_empty() || !bar.is_empty()) {
+                width += 1 + end_width;
+            } else if !end_value.is_empty() {
+                width += 1 + label_width(end_value);
+            }
+            if !right_label.is_empty() {
+                width += 1 + label_width(right_label);
+            }
+            if !bar.is_empty() {
+                width += match right_label.is_empty() {
+                    true => right_width + 2,
+                    false => right_width - label_width(right_label) + 1,
+                } + label_width(bar);
+            }
+            widths.push(width);
+        }
+
+        if let Some(title) = &self.y_title {
+            widths.push(match self.y_label_position {
+                LabelPosition::Right => text_width + 1 + label_width(title),
+                _ => label_width(title),
+            });
+        }
+        let indent = if boxed { indent + 1 } else { indent };
+        if !self.hide_x_labels {
+            widths.push(indent + self.x_label_line_width());
+        }
+        if let Some(title) = &self.x_title {
+            let title = label_width(title);
+            widths.push(indent + columns.saturating_sub(title) / 2 + title);
+        }
+        if self.legend {
+            widths.push(label_width(&self.legend_line()));
+        }
+        if self.stats {
+            widths.extend(self.stats_lines().iter().map(|line| label_width(line)));
+        }
+        for text in self.caption.iter().chain(&self.footer) {
+            widths.extend(wrap(text, text_width).iter().map(|line| label_width(line)));
+        }
+
+        widths.into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns the number of text rows and columns of the canvas.
+    fn canvas_cells(&self) -> (usize, usize) {
+        (
+            (self.height / 4 + 1) as usize,
+            (self.width / 2 + 1) as usize,
+        )
+    }
+
+    /// Returns the width taken left of the canvas by the gutter and the space after it.
+    fn gutter_width(&self) -> usize {
+        let (rows, _) = self.canvas_cells();
+        match self.y_label_sides(rows).2 {
+            Some(gutter) => gutter + 1,
+            None => 0,
+        }
+    }
+
+    /// Returns the width of the x-axis labels line, without the indent.
+    fn x_label_line_width(&self) -> usize {
+        if !self.x_ticks.is_empty() {
+            return label_width(&self.custom_x_tick_line());
+        }
+
+        // the middle label is formatted along with the others, but fits between them
+        let values = match self.x_label_middle {
+            true => vec![self.xmin, (self.xmin + self.xmax) / 2.0, self.xmax],
+            false => vec![self.xmin, self.xmax],
+        };
+        let labels = self.format_x(|format| format.format_ticks(&values, (self.xmin, self.xmax)));
+        let xmin = labels.first().map_or(0, |label| label_width(label));
+        let xmax = labels.last().map_or(0, |label| label_width(label));
+        ((self.width as usize) / 2).max(xmin + xmax + 1)
+    }
+
+    /// Adds the shape to the collection, rescaling its axis if it is auto-ranged.
+    fn add_series(&mut self, series: SeriesShape<'a>, color: Option<RGB8>, axis: YAxis) {
+        let shape = series.get();
+        let (samples, envelope) = self.sample(&shape);
+        let (ymin, ymax) = self.series_bounds(&shape, &samples, &envelope);
+        let points = match shape.function() {
+            Some(_) => samples.len(),
+            None => data_points(&shape).count(),
+        };
+        drop(shape);
+
+        self.series.push(SeriesInfo {
+            name: None,
+            color,
+            points,
+        });
+        self.shapes.push(Series {
+            shape: series,
+            color,
+            axis,
+            samples,
+            envelope,
+            z: 0,
+            density: false,
+        });
+
+        self.extend_axis_range(axis, (ymin, ymax));
+    }
+
+    /// Extends the data range of the axis so that it covers the given bounds.
+    fn extend_axis_range(&mut self, axis: YAxis, (ymin, ymax): (f32, f32)) {
+        match axis {
+            YAxis::Primary => self.extend_y_range((ymin, ymax)),
+            YAxis::Secondary => {
+                let (data_ymin, data_ymax) = self.data_y2_range;
+                self.data_y2_range = (f32::min(data_ymin, ymin), f32::max(data_ymax, ymax));
+                self.pad_y_ranges();
+            }
+        }
+    }
+
+    /// Adds the user-defined geometry, extending the y range by its bounds if it reports any.
+    fn add_drawable(&mut self, drawable: &'a dyn Drawable, color: Option<RGB8>) {
+        self.drawables.push((drawable, color));
+        if let Some(bounds) = drawable.y_bounds() {
+            self.extend_y_range(bounds);
+        }
+    }
+
+    /// Extends the primary data range so that it covers the given bounds.
+    fn extend_y_range(&mut self, (ymin, ymax): (f32, f32)) {
+        let (data_ymin, data_ymax) = self.data_y_range;
+        self.data_y_range = (f32::min(data_ymin, ymin), f32::max(data_ymax, ymax));
+        self.pad_y_ranges();
+    }
+
+    /// Recalculates automatic y ranges from the data ranges, adding the padding.
+    fn pad_y_ranges(&mut self) {
+        let pad = |(ymin, ymax): (f32, f32), fraction: f32| {
+            // nothing finite was plotted on the axis
+            if ymin > ymax {
+                return (0.0, 1.0);
+            }
+            let margin = if ymax > ymin {
+                (ymax - ymin) * fraction
+            } else if ymin == 0.0 {
+                0.5
+            } else {
+                // flat data is padded relative to its value, by half of it without any padding
+                ymin.abs() * if fraction > 0.0 { fraction } else { 0.5 }
+            };
+            (ymin - margin, ymax + margin)
+        };
+
+        if self.y_ranging == ChartRangeMethod::AutoRange {
+            (self.ymin, self.ymax) = pad(self.data_y_range, self.y_padding);
+        }
+        if self.y2_ranging == ChartRangeMethod::AutoRange {
+            (self.y2min, self.y2max) = pad(self.data_y2_range, self.y_padding);
+        }
+    }
+
+    /// Returns the smallest and largest y values of the shape within the x range,
+    /// taking the values of continuous shapes from their samples. Values that aren't finite
+    /// are left out, and a shape without any gives an empty range, with the minimum above
+    /// the maximum, which leaves the data range as it is.
+    fn y_bounds(&self, shape: &Shape, samples: &[f32]) -> (f32, f32) {
+        // rescale ymin and ymax
+        let ys: Vec<_> = match shape {
+            Shape::Continuous(_) | Shape::ParallelContinuous(_) => {
+                samples.iter().copied().filter(|y| y.is_finite()).collect()
+            }
+            Shape::Circle { center, radius } => {
+                vec![center.1 - radius.abs(), center.1 + radius.abs()]
+            }
+            Shape::Ellipse { center, radii } => {
+                vec![center.1 - radii.1.abs(), center.1 + radii.1.abs()]
+            }
+            _ => self
+                .drawn_points(shape)
+                .filter(|(x, _)| *x >= self.xmin && *x <= self.xmax)
+                .map(|(_, y)| y)
+                .collect(),
+        };
+
+        ys.into_iter()
+            .filter(|y| y.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(ymin, ymax), y| {
+                (ymin.min(y), ymax.max(y))
+            })
+    }
+}
+
+impl Chart<'_> {
+    /// Iterates over the points of the shape the way they are drawn, which is the curve
+    /// of `Shape::Bezier` and the data of the other shapes, see `data_points`.
+    fn drawn_points<'s>(
+        &self,
+        shape: &'s Shape,
+    ) -> Box<dyn DoubleEndedIterator<Item = (f32, f32)> + 's> {
+        match shape {
+            Shape::Bezier(dt) => Box::new(bezier(dt, self.width as usize).into_iter()),
+            _ => data_points(shape),
+        }
+    }
+
+    /// Evaluates the function of a continuous shape at every canvas column, returning the
+    /// values along with their envelope.
+    ///
+    /// With several samples per column, the function is also evaluated between the columns,
+    /// up to the start of the next one, and the envelope holds the smallest and largest
+    /// finite values within every column. It is empty with a single sample per column.
+    /// Every position is evaluated once, the last one of a column being the first one
+    /// of the next.
+    fn sample(&self, shape: &Shape) -> (Vec<f32>, Vec<(f32, f32)>) {
+        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
+        let n = self.samples_per_column.max(1);
+        let count = if n > 1 {
+            self.width * n + 1
+        } else {
+            self.width
+        };
+        let x = |k: u32| x_scale.inv_linear(k as f32 / n as f32);
+
+        let values: Vec<f32> = match shape {
+            #[cfg(feature = "rayon")]
+            Shape::ParallelContinuous(f) => {
+                use rayon::prelude::*;
+
+                (0..count).into_par_iter().map(|k| f(x(k))).collect()
+            }
+            _ => match shape.function() {
+                Some(f) => (0..count).map(|k| f(x(k))).collect(),
+                None => return (Vec::new(), Vec::new()),
+            },
+        };
+        if n == 1 {
+            return (values, Vec::new());
+        }
+
+        let n = n as usize;
+        let samples = values
+            .iter()
+            .step_by(n)
+            .take(self.width as usize)
+            .copied()
+            .collect();
+        let envelope = values
+            .windows(n + 1)
+            .step_by(n)
+            .map(|column| {
+                column
+                    .iter()
+                    .filter(|y| y.is_finite())
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &y| {
+                        (lo.min(y), hi.max(y))
+                    })
+            })
+            .collect();
+        (samples, envelope)
+    }
+
+    /// Returns the bounds of the shape like `y_bounds`, extended by its envelope.
+    fn series_bounds(&self, shape: &Shape, samples: &[f32], envelope: &[(f32, f32)]) -> (f32, f32) {
+        envelope
+            .iter()
+            .fold(self.y_bounds(shape, samples), |(ymin, ymax), &(lo, hi)| {
+                (ymin.min(lo), ymax.max(hi))
+            })
+    }
+}
+
+/// Implements a builder trait for `Chart` along with a consuming counterpart of every
+/// method, which takes and returns the chart by value, for building a chart in a single
+/// expression. Both run the same body, as a chart kept by value can't be lent to
+/// the trait methods for as long as they borrow it.
+macro_rules! builder {
+    (
+        $(#[$doc:meta])*
+        impl $trait:ident {
+            $(
+                $(#[$attr:meta])*
+                fn $name:ident(&mut $self:ident $(, $arg:ident: $ty:ty)*) => $with:ident $body:block
+            )*
+        }
+    ) => {
+        impl<'a> $trait<'a> for Chart<'a> {
+            $(
+                $(#[$attr])*
+                fn $name(&mut $self $(, $arg: $ty)*) -> &mut Self $body
+            )*
+        }
+
+        $(#[$doc])*
+        impl<'a> Chart<'a> {
+            $(
+                #[doc = concat!(
+                    "Same as `", stringify!($trait), "::", stringify!($name),
+                    "`, but takes and returns the chart by value."
+                )]
+                pub fn $with(mut $self $(, $arg: $ty)*) -> Self $body
+            )*
+        }
+    };
+}
+
+builder! {
+    impl ColorPlot {
+        fn linecolorplot(&mut self, shape: &'a Shape, color: RGB8) => with_linecolorplot {
+            self.add_series(SeriesShape::Borrowed(shape), Some(color), YAxis::Primary);
+            self
+        }
+
+        fn linecolorplot_secondary(&mut self, shape: &'a Shape, color: RGB8)
+            => with_linecolorplot_secondary {
+            self.add_series(SeriesShape::Borrowed(shape), Some(color), YAxis::Secondary);
+            self
+        }
+
+        fn customcolorplot(&mut self, drawable: &'a dyn Drawable, color: RGB8)
+            => with_customcolorplot {
+            self.add_drawable(drawable, Some(color));
+            self
+        }
+    }
+}
+
+builder! {
+    /// Consuming counterparts of the builder methods, for building a chart in a single
+    /// expression that can be returned or stored, as the builder traits keep the chart
+    /// borrowed for as long as its shapes. Every builder trait method has one, named
+    /// after it with a `with_` prefix, except `TextBuilder::with_stats`, whose
+    /// counterpart is `with_stats_shown`.
+    ///
+    /// ```rust
+    /// use textplots::{BorderStyle, Chart, LineStyle, Shape};
+    ///
+    /// struct Report<'a> {
+    ///     chart: Chart<'a>,
+    /// }
+    ///
+    /// let wave = Shape::Continuous(Box::new(|x| x.sin()));
+    /// let mut report = Report {
+    ///     chart: Chart::new(120, 60, -5.0, 5.0)
+    ///         .with_lineplot(&wave)
+    ///         .with_series_name("wave")
+    ///         .with_caption("y = sin(x)")
+    ///         .with_legend(true)
+    ///         .with_border_style(BorderStyle::Box)
+    ///         .with_x_axis_style(LineStyle::Solid)
+    ///         .with_samples_per_column(4)
+    ///         .with_stats_shown(true),
+    /// };
+    ///
+    /// report.chart.display();
+    /// ```
+    impl Plot {
+        fn lineplot(&mut self, shape: &'a Shape) => with_lineplot {
+            self.add_series(SeriesShape::Borrowed(shape), None, YAxis::Primary);
+            self
+        }
+
+        fn lineplot_secondary(&mut self, shape: &'a Shape) => with_lineplot_secondary {
+            self.add_series(SeriesShape::Borrowed(shape), None, YAxis::Secondary);
+            self
+        }
+
+        fn customplot(&mut self, drawable: &'a dyn Drawable) => with_customplot {
+            self.add_drawable(drawable, None);
+            self
+        }
+
+        fn plot_dataset(&mut self, dataset: &'a Dataset) => with_plot_dataset {
+            for (k, column) in dataset.columns().iter().enumerate() {
+                let color = column
+                    .color()
+                    .unwrap_or(dataset::PALETTE[k % dataset::PALETTE.len()]);
+                let shape = SeriesShape::Lines(column.points());
+                self.add_series(shape, Some(color), YAxis::Primary);
+                if let Some(series) = self.series.last_mut() {
+                    series.name = Some(column.name().to_string());
+                }
+            }
+            self.legend = true;
+            self
+        }
+    }
+}
+
+/// Returns the polyline given in canvas coordinates with every segment replaced by points
+/// along the interpolating curve, about one per canvas point. Runs of finite points are
+/// interpolated separately, keeping the points in between as breaks.
+fn interpolate(points: &[(f32, f32)], interpolation: Interpolation) -> Vec<(f32, f32)> {
+    if let Interpolation::Linear = interpolation {
+        return points.to_vec();
+    }
+
+    let mut curve = Vec::with_capacity(points.len());
+    let is_finite = |(x, y): &(f32, f32)| x.is_finite() && y.is_finite();
+    let mut rest = points;
+
+    while !rest.is_empty() {
+        let run = rest.len() - rest.iter().skip_while(|p| is_finite(p)).count();
+        let (finite, tail) = rest.split_at(run);
+        let breaks = tail.len() - tail.iter().skip_while(|p| !is_finite(p)).count();
+
+        match interpolation {
+            Interpolation::CatmullRom => curve.extend(catmull_rom(finite)),
+            Interpolation::Monotone => curve.extend(monotone_cubic(finite)),
+            Interpolation::Linear => unreachable!(),
+        }
+        curve.extend_from_slice(&tail[..breaks]);
+        rest = &tail[breaks..];
+    }
+
+    curve
+}
+
+/// Returns the number of pieces the segment is split into, so that they're about a point long.
+fn subdivisions((x1, y1): (f32, f32), (x2, y2): (f32, f32)) -> usize {
+    ((x2 - x1).abs().max((y2 - y1).abs()).ceil() as usize).clamp(1, 1024)
+}
+
+/// Returns the canvas points of the outline of the ellipse with the given center and radii
+/// in canvas points, clipped to the canvas.
+///
+/// The outline is solved for every visible column and every visible row, which keeps it
+/// connected and only costs as much as the canvas is large, however large the ellipse.
+fn ellipse_outline(
+    (cx, cy): (f64, f64),
+    (a, b): (f64, f64),
+    width: u32,
+    height: u32,
+) -> Vec<(u32, u32)> {
+    let mut outline = Vec::new();
+    if ![cx, cy, a, b].iter().all(|v| v.is_finite()) {
+        return outline;
+    }
+
+    // half the chord of the ellipse at `offset` from the center along the other radius
+    let half_chord = |radius: f64, other: f64, offset: f64| {
+        let t = if other > 0.0 { offset / other } else { 0.0 };
+        (radius * (1.0 - t * t).sqrt()).round()
+    };
+    let visible = |low: f64, high: f64, size: u32| {
+        let (low, high) = (low.max(0.0).ceil(), high.min(size as f64).floor());
+        (low as u32)..=(high as u32).min(size)
+    };
+
+    if cx - a <= width as f64 && cx + a >= 0.0 {
+        for x in visible(cx - a, cx + a, width) {
+            let dy = half_chord(b, a, x as f64 - cx);
+            for y in [cy - dy, cy + dy] {
+                if (0.0..=height as f64).contains(&y) {
+                    outline.push((x, y as u32));
+                }
+            }
+        }
+    }
+    if cy - b <= height as f64 && cy + b >= 0.0 {
+        for y in visible(cy - b, cy + b, height) {
+            let dx = half_chord(a, b, y as f64 - cy);
+            for x in [cx - dx, cx + dx] {
+                if (0.0..=width as f64).contains(&x) {
+                    outline.push((x as u32, y));
+                }
+            }
+        }
+    }
+
+    outline
+}
+
+/// Returns the runs of missing values as the x values of the points around them,
+/// `None` standing for a run at the start or the end of the data.
+fn gaps(data: &[(f32, Option<f32>)]) -> Vec<(Option<f32>, Option<f32>)> {
+    let mut gaps = Vec::new();
+    let mut last = None;
+    let mut missing = false;
+
+    for &(x, y) in data {
+        match y {
+            Some(_) => {
+                if missing {
+                    gaps.push((last, Some(x)));
+                    missing = false;
+                }
+                last = Some(x);
+            }
+            None => missing = true,
+        }
+    }
+    if missing {
+        gaps.push((last, None));
+    }
+
+    gaps
+}
+
+/// Returns the spans of canvas points inside the polygon given in (unclamped) canvas
+/// coordinates as `(y, x1, x2)`, following the even-odd rule and clipped to the canvas.
+fn scanline_fill(corners: &[(f32, f32)], width: u32, height: u32) -> Vec<(u32, u32, u32)> {
+    let mut spans = Vec::new();
+    if corners.len() < 3 {
+        return spans;
+    }
+
+    let edges: Vec<_> = corners
+        .iter()
+        .zip(corners.iter().cycle().skip(1))
+        .filter(|((x1, y1), (x2, y2))| {
+            x1.is_finite() && y1.is_finite() && x2.is_finite() && y2.is_finite()
+        })
+        .collect();
+
+    for y in 0..=height {
+        let scan = y as f32;
+        // half-open on the edges, so that shared corners are crossed once
+        let mut crossings: Vec<f32> = edges
+            .iter()
+            .filter(|((_, y1), (_, y2))| (*y1 <= scan) != (*y2 <= scan))
+            .map(|((x1, y1), (x2, y2))| x1 + (scan - y1) * (x2 - x1) / (y2 - y1))
+            .collect();
+        crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(cmp::Ordering::Equal));
+
+        for pair in crossings.chunks_exact(2) {
+            let x1 = pair[0].ceil().max(0.0);
+            let x2 = pair[1].floor().min(width as f32);
+            if x1 <= x2 {
+                spans.push((y, x1 as u32, x2 as u32));
+            }
+        }
+    }
+
+    spans
+}
+
+/// Returns the points of the Bézier curve of the control points, splitting it into the given
+/// number of pieces with de Casteljau's algorithm.
+fn bezier(control: &[(f32, f32)], pieces: usize) -> Vec<(f32, f32)> {
+    if control.len() < 2 {
+        return control.to_vec();
+    }
+
+    let pieces = pieces.max(1);
+    let mut work = Vec::with_capacity(control.len());
+    (0..=pieces)
+        .map(|i| {
+            let t = i as f32 / pieces as f32;
+            work.clear();
+            work.extend_from_slice(control);
+            for n in (1..control.len()).rev() {
+                for k in 0..n {
+                    let ((x1, y1), (x2, y2)) = (work[k], work[k + 1]);
+                    work[k] = (x1 + (x2 - x1) * t, y1 + (y2 - y1) * t);
+                }
+            }
+            work[0]
+        })
+        .collect()
+}
+
+/// Returns the points of the uniform Catmull-Rom spline through the given points.
+fn catmull_rom(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut curve: Vec<(f32, f32)> = points.first().copied().into_iter().collect();
+
+    for k in 1..points.len() {
+        let (p1, p2) = (points[k - 1], points[k]);
+        // the ends act as their own neighbours
+        let p0 = points[k.saturating_sub(2)];
+        let p3 = points[(k + 1).min(points.len() - 1)];
+
+        let pieces = subdivisions(p1, p2);
+        for i in 1..=pieces {
+            let t = i as f32 / pieces as f32;
+            let spline = |a: f32, b: f32, c: f32, d: f32| {
+                0.5 * (2.0 * b
+                    + (c - a) * t
+                    + (2.0 * a - 5.0 * b + 4.0 * c - d) * t * t
+                    + (3.0 * b - a - 3.0 * c + d) * t * t * t)
+            };
+            curve.push((
+                spline(p0.0, p1.0, p2.0, p3.0),
+                spline(p0.1, p1.1, p2.1, p3.1),
+            ));
+        }
+    }
+
+    curve
+}
+
+/// Returns the points of the monotone cubic spline through the given points, computing the
+/// tangents with the Fritsch-Carlson method. Falls back to straight segments unless the x
+/// values are strictly increasing.
+fn monotone_cubic(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    if points.len() < 3 || points.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+        return points.to_vec();
+    }
+
+    let slopes: Vec<f32> = points
+        .windows(2)
+        .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+        .collect();
+
+    let mut tangents = Vec::with_capacity(points.len());
+    tangents.push(slopes[0]);
+    for pair in slopes.windows(2) {
+        tangents.push(if pair[0] * pair[1] <= 0.0 {
+            0.0
+        } else {
+            (pair[0] + pair[1]) / 2.0
+        });
+    }
+    tangents.push(slopes[slopes.len() - 1]);
+
+    // limit the tangents so that the curve doesn't overshoot
+    for (k, &slope) in slopes.iter().enumerate() {
+        if slope == 0.0 {
+            tangents[k] = 0.0;
+            tangents[k + 1] = 0.0;
+            continue;
+        }
+
+        let (alpha, beta) = (tangents[k] / slope, tangents[k + 1] / slope);
+        let norm = alpha * alpha + beta * beta;
+        if norm > 9.0 {
+            let tau = 3.0 / norm.sqrt();
+            tangents[k] = tau * alpha * slope;
+            tangents[k + 1] = tau * beta * slope;
+        }
+    }
+
+    let mut curve = vec![points[0]];
+    for k in 1..points.len() {
+        let ((x1, y1), (x2, y2)) = (points[k - 1], points[k]);
+        let (m1, m2) = (tangents[k - 1], tangents[k]);
+        let h = x2 - x1;
+
+        let pieces = subdivisions(points[k - 1], points[k]);
+        for i in 1..=pieces {
+            let t = i as f32 / pieces as f32;
+            let (t2, t3) = (t * t, t * t * t);
+            let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y1
+                + (t3 - 2.0 * t2 + t) * h * m1
+                + (-2.0 * t3 + 3.0 * t2) * y2
+                + (t3 - t2) * h * m2;
+            curve.push((x1 + t * h, y));
+        }
+    }
+
+    curve
+}
+
+/// Clips the polyline given in (unclamped) canvas coordinates to the canvas bounds,
+/// returning the visible parts of it as separate polylines.
+fn clip_polyline(points: &[(f32, f32)], width: u32, height: u32) -> Vec<Vec<(u32, u32)>> {
+    let mut polylines: Vec<Vec<(u32, u32)>> = Vec::new();
+
+    for pair in points.windows(2) {
+        let clipped = clip_segment(pair[0], pair[1], width as f32, height as f32);
+        let ((x1, y1), (x2, y2)) = match clipped {
+            Some(segment) => segment,
+            None => continue,
+        };
+        let start = (x1.round() as u32, y1.round() as u32);
+        let end = (x2.round() as u32, y2.round() as u32);
+
+        match polylines.last_mut() {
+            Some(polyline) if polyline.last() == Some(&start) => polyline.push(end),
+            _ => polylines.push(vec![start, end]),
+        }
+    }
+
+    polylines
+}
+
+/// Clips the segment to the `[0, width] x [0, height]` rectangle
+/// using the Cohen-Sutherland algorithm, or returns `None` if it lies outside (or isn't finite).
+fn clip_segment(
+    mut p1: (f32, f32),
+    mut p2: (f32, f32),
+    width: f32,
+    height: f32,
+) -> Option<((f32, f32), (f32, f32))> {
+    const LEFT: u8 = 1;
+    const RIGHT: u8 = 2;
+    const TOP: u8 = 4;
+    const BOTTOM: u8 = 8;
+
+    let outcode = |(x, y): (f32, f32)| {
+        let mut code = 0;
+        if x < 0.0 {
+            code |= LEFT;
+        } else if x > width {
+            code |= RIGHT;
+        }
+        if y < 0.0 {
+            code |= TOP;
+        } else if y > height {
+            code |= BOTTOM;
+        }
+        code
+    };
+
+    if [p1.0, p1.1, p2.0, p2.1].iter().any(|v| !v.is_finite()) {
+        return None;
+    }
+
+    let (mut code1, mut code2) = (outcode(p1), outcode(p2));
+    loop {
+        if code1 | code2 == 0 {
+            return Some((p1, p2));
+        }
+        if code1 & code2 != 0 {
+            return None;
+        }
+
+        // move the outside point onto the boundary it crosses
+        let code = if code1 != 0 { code1 } else { code2 };
+        let (x1, y1) = p1;
+        let (x2, y2) = p2;
+        let point = if code & TOP != 0 {
+            (x1 + (x2 - x1) * (0.0 - y1) / (y2 - y1), 0.0)
+        } else if code & BOTTOM != 0 {
+            (x1 + (x2 - x1) * (height - y1) / (y2 - y1), height)
+        } else if code & RIGHT != 0 {
+            (width, y1 + (y2 - y1) * (width - x1) / (x2 - x1))
+        } else {
+            (0.0, y1 + (y2 - y1) * (0.0 - x1) / (x2 - x1))
+        };
+
+        if code == code1 {
+            p1 = point;
+            code1 = outcode(p1);
+        } else {
+            p2 = point;
+            code2 = outcode(p2);
+        }
+    }
+}
+
+/// Tells whether the function jumps between `x1` and `x2` rather than changing steeply but continuously,
+/// by checking that its value at the midpoint doesn't lie between the values at the ends.
+fn is_discontinuous(f: &dyn Fn(f32) -> f32, (x1, y1): (f32, f32), (x2, y2): (f32, f32)) -> bool {
+    let ym = f((x1 + x2) / 2.0);
+    if !ym.is_finite() {
+        return true;
+    }
+
+    let margin = (y2 - y1).abs() * 0.01;
+    ym < y1.min(y2) - margin || ym > y1.max(y2) + margin
+}
+
+/// Reduces every run of consecutive points sharing a canvas column to its first, minimum,
+/// maximum and last points (in their original order), which draw the same line segments.
+fn downsample_columns(points: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut output = Vec::new();
+
+    for run in points.chunk_by(|a, b| a.0 == b.0) {
+        if run.len() <= 4 {
+            output.extend_from_slice(run);
+            continue;
+        }
+
+        let last = run.len() - 1;
+        let (imin, _) = run
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (_, y))| *y)
+            .unwrap_or((0, &run[0]));
+        let (imax, _) = run
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (_, y))| *y)
+            .unwrap_or((0, &run[0]));
+
+        let mut keep = [0, imin, imax, last];
+        keep.sort_unstable();
+        let mut prev = None;
+        for i in keep {
+            if prev != Some(i) {
+                output.push(run[i]);
+                prev = Some(i);
+            }
+        }
+    }
+
+    output
+}
+
+/// Sets a sparse hatching over the given canvas points, so that the shading doesn't drown
+/// the data drawn over it.
+fn hatch(
+    canvas: &mut BrailleCanvas,
+    columns: RangeInclusive<u32>,
+    rows: RangeInclusive<u32>,
+    color: &Option<RGB8>,
+) {
+    for i in columns.filter(|i| i % 2 == 0) {
+        for j in rows.clone().filter(|j| (i / 2 + j) % 4 == 0) {
+            if let Some(color) = color {
+                canvas.set_colored(i, j, rgb_to_pixelcolor(color));
+            } else {
+                canvas.set(i, j);
+            }
+        }
+    }
+}
+
+/// Translates a range of data values into the canvas points it covers along an axis of the
+/// given size, or `None` if it lies outside of the axis.
+fn span_pixels(scale: &Scale, values: &Range<f32>, size: u32) -> Option<RangeInclusive<u32>> {
+    let (a, b) = (
+        scale.linear_unclamped(values.start),
+        scale.linear_unclamped(values.end),
+    );
+    let (from, to) = (a.min(b), a.max(b));
+    if !(from <= size as f32 && to >= 0.0) {
+        return None;
+    }
+    Some(from.max(0.0).round() as u32..=to.min(size as f32).round() as u32)
+}
+
+/// Draws a bar in every canvas column from the smallest to the largest of its values, leaving
+/// out columns without any, which have the smallest value above the largest.
+fn draw_envelope(
+    canvas: &mut BrailleCanvas,
+    columns: &[(f32, f32)],
+    y_scale: &Scale,
+    height: u32,
+    color: &Option<RGB8>,
+) {
+    for (i, &(lo, hi)) in (0..).zip(columns) {
+        if lo > hi {
+            continue;
+        }
+
+        let j1 = height - y_scale.linear(hi).round() as u32;
+        let j2 = height - y_scale.linear(lo).round() as u32;
+        if let Some(color) = color {
+            let color = rgb_to_pixelcolor(color);
+            canvas.line_colored(i, j1, i, j2, color);
+        } else {
+            canvas.line(i, j1, i, j2);
+        }
+    }
+}
+
+/// Writes the labels of labeled points in the cells next to their points, where they don't
+/// overlap the points or other labels, preferring cells without any dots set.
+fn place_labels(canvas: &mut BrailleCanvas, labels: &[(u32, u32, &str)], width: u32, height: u32) {
+    let (columns, rows) = (width / 2 + 1, height / 4 + 1);
+    let mut taken: HashSet<(u32, u32)> = labels.iter().map(|&(i, j, _)| (i / 2, j / 4)).collect();
+    let empty = |canvas: &BrailleCanvas, (column, row): (u32, u32)| {
+        (0..2).all(|dx| (0..4).all(|dy| !canvas.get(column * 2 + dx, row * 4 + dy)))
+    };
+
+    for &(i, j, label) in labels {
+        let (column, row) = (i / 2, j / 4);
+        let len = label.chars().count() as u32;
+        let left = column.checked_sub(len);
+        let candidates = [
+            Some((column + 1, row)),
+            left.map(|start| (start, row)),
+            row.checked_sub(1).map(|above| (column, above)),
+            Some((column, row + 1)),
+        ];
+        let fits = |&(start, row): &(u32, u32)| {
+            start + len <= columns
+                && row < rows
+                && (start..start + len).all(|c| !taken.contains(&(c, row)))
+        };
+        let spot = candidates
+            .iter()
+            .flatten()
+            .filter(|spot| fits(spot))
+            .find(|&&(start, row)| (start..start + len).all(|c| empty(canvas, (c, row))))
+            .or_else(|| candidates.iter().flatten().find(|spot| fits(spot)))
+            .copied();
+
+        if let Some((start, row)) = spot {
+            for (c, ch) in (start..).zip(label.chars()) {
+                canvas.set_char(c * 2, row * 4, ch);
+                taken.insert((c, row));
+            }
+        }
+    }
+}
+
+/// Sets the dots of the layer a colored series was drawn on in the canvas, coloring the cells
+/// already taken by other colored series as specified. `cells` keeps the sum of the colors
+/// given to each cell, along with their number.
+fn merge_layer(
+    canvas: &mut BrailleCanvas,
+    layer: &BrailleCanvas,
+    (width, height): (u32, u32),
+    color: RGB8,
+    mixing: ColorMixing,
+    cells: &mut HashMap<(u32, u32), ([u32; 3], u32)>,
+) {
+    let mut merged = HashMap::new();
+
+    for y in 0..=height {
+        for x in (0..=width).filter(|&x| layer.get(x, y)) {
+            let cell = *merged.entry((x / 2, y / 4)).or_insert_with(|| {
+                let (sum, count) = cells.entry((x / 2, y / 4)).or_insert(([0; 3], 0));
+                if *count == 0 || mixing == ColorMixing::Blend {
+                    for (total, value) in sum.iter_mut().zip([color.r, color.g, color.b]) {
+                        *total += value as u32;
+                    }
+                    *count += 1;
+                }
+                let [r, g, b] = sum.map(|total| (total / *count) as u8);
+                RGB8::new(r, g, b)
+            });
+            canvas.set_colored(x, y, rgb_to_pixelcolor(&cell));
+        }
+    }
+}
+
+/// Spreads the scatter points over the dots of the character cells they land in, one more
+/// dot for every point up to a full cell, returning the dots with the number of points
+/// in their cell.
+fn density_dots(points: &[(u32, u32)], width: u32, height: u32) -> Vec<(u32, u32, usize)> {
+    // an order filling the cell evenly, as (column, row) within the 2x4 dots of a cell
+    const FILL: [(u32, u32); 8] = [
+        (0, 0),
+        (1, 3),
+        (1, 1),
+        (0, 2),
+        (0, 1),
+        (1, 2),
+        (1, 0),
+        (0, 3),
+    ];
+
+    let mut cells: BTreeMap<(u32, u32), usize> = BTreeMap::new();
+    for &(x, y) in points {
+        *cells.entry((x / 2, y / 4)).or_default() += 1;
+    }
+
+    let mut dots = Vec::new();
+    for ((column, row), hits) in cells {
+        for &(dx, dy) in FILL.iter().take(hits.min(FILL.len())) {
+            let (x, y) = (column * 2 + dx, row * 4 + dy);
+            if x <= width && y <= height {
+                dots.push((x, y, hits.min(FILL.len())));
+            }
+        }
+    }
+    dots
+}
+
+/// Drops scatter points that land on an already occupied canvas pixel.
+fn dedup_pixels(points: Vec<(u32, u32)>, width: u32, height: u32) -> Vec<(u32, u32)> {
+    let stride = width as usize + 1;
+    let mut seen = vec![false; stride * (height as usize + 1)];
+
+    points
+        .into_iter()
+        .filter(|&(x, y)| {
+            let idx = y as usize * stride + x as usize;
+            match seen.get_mut(idx) {
+                Some(taken) if *taken => false,
+                Some(taken) => {
+                    *taken = true;
+                    true
+                }
+                None => true,
+            }
+        })
+        .collect()
+}
+
+fn rgb_to_pixelcolor(rgb: &RGB8) -> PixelColor {
+    PixelColor::TrueColor {
         r: rgb.r,
         g: rgb.g,
         b: rgb.b,
     }
 }
 
-impl<'a> AxisBuilder<'a> for Chart<'a> {
-    fn x_axis_style(&'a mut self, style: LineStyle) -> &'a mut Chart {
-        self.x_style = style;
-        self
+builder! {
+    impl AxisBuilder {
+        fn x_axis_style(&mut self, style: LineStyle) => with_x_axis_style {
+            self.x_style = style;
+            self
+        }
+
+        fn y_axis_style(&mut self, style: LineStyle) => with_y_axis_style {
+            self.y_style = style;
+            self
+        }
+
+        fn axis_color(&mut self, color: RGB8) => with_axis_color {
+            self.axis_color = Some(color);
+            self
+        }
+
+        fn axis_position(&mut self, position: AxisPosition) => with_axis_position {
+            self.axis_position = position;
+            self
+        }
+
+        fn y_break(&mut self, gap: Range<f32>) => with_y_break {
+            self.y_break = Some(gap);
+            self
+        }
+
+        fn axis_arrows(&mut self, enabled: bool) => with_axis_arrows {
+            self.axis_arrows = enabled;
+            self
+        }
+
+        fn origin_marker(&mut self, enabled: bool) => with_origin_marker {
+            self.origin_marker = enabled;
+            self
+        }
+
+        fn y2_range(&mut self, ymin: f32, ymax: f32) => with_y2_range {
+            self.y2min = ymin;
+            self.y2max = ymax;
+            self.y2_ranging = ChartRangeMethod::FixedRange;
+            self
+        }
     }
+}
 
-    fn y_axis_style(&'a mut self, style: LineStyle) -> &'a mut Chart {
-        self.y_style = style;
-        self
+builder! {
+    impl BorderBuilder {
+        fn border_style(&mut self, style: BorderStyle) => with_border_style {
+            self.border_style = Some(style);
+            self
+        }
     }
 }
 
-impl<'a> LabelBuilder<'a> for Chart<'a> {
-    /// Specifies a formater for the x-axis label.
-    fn x_label_format(&mut self, format: LabelFormat) -> &mut Self {
-        self.x_label_format = format;
-        self
+builder! {
+    impl LabelBuilder {
+        /// Specifies a formater for the x-axis label.
+        fn x_label_format(&mut self, format: LabelFormat) => with_x_label_format {
+            self.x_label_format = format;
+            self
+        }
+
+        /// Specifies a formater for the y-axis label.
+        fn y_label_format(&mut self, format: LabelFormat) => with_y_label_format {
+            self.y_label_format = format;
+            self
+        }
+
+        /// Specifies a formater for the secondary y-axis label.
+        fn y2_label_format(&mut self, format: LabelFormat) => with_y2_label_format {
+            self.y2_label_format = format;
+            self
+        }
+
+        /// Specifies the side of the y-axis labels.
+        fn y_label_position(&mut self, position: LabelPosition) => with_y_label_position {
+            self.y_label_position = position;
+            self
+        }
+
+        /// Specifies the color of the axis labels.
+        fn label_color(&mut self, color: RGB8) => with_label_color {
+            self.label_color = Some(color);
+            self
+        }
+
+        /// Specifies whether the middle of the x-axis is labeled.
+        fn x_label_middle(&mut self, enabled: bool) => with_x_label_middle {
+            self.x_label_middle = enabled;
+            self
+        }
+
+        fn end_values(&mut self, enabled: bool) => with_end_values {
+            self.end_values = enabled;
+            self
+        }
     }
+}
 
-    /// Specifies a formater for the y-axis label.
-    fn y_label_format(&mut self, format: LabelFormat) -> &mut Self {
-        self.y_label_format = format;
-        self
+builder! {
+    impl ShapeStyleBuilder {
+        fn baseline(&mut self, y: f32) => with_baseline {
+            self.baseline = Some(y);
+            self
+        }
+
+        fn baseline_bottom(&mut self) => with_baseline_bottom {
+            self.baseline = None;
+            self
+        }
+
+        fn step_style(&mut self, style: StepStyle) => with_step_style {
+            self.step_style = style;
+            self
+        }
+
+        fn interpolation(&mut self, interpolation: Interpolation) => with_interpolation {
+            self.interpolation = interpolation;
+            self
+        }
+
+        fn polygon_fill(&mut self, enabled: bool) => with_polygon_fill {
+            self.polygon_fill = enabled;
+            self
+        }
+
+        fn shade_gaps(&mut self, enabled: bool) => with_shade_gaps {
+            self.shade_gaps = enabled;
+            self
+        }
+
+        fn color_mixing(&mut self, mixing: ColorMixing) => with_color_mixing {
+            self.color_mixing = mixing;
+            self
+        }
     }
 }
 
-impl<'a> TickDisplayBuilder<'a> for Chart<'a> {
-    /// Specifies the density of y-axis tick labels
-    fn y_tick_display(&mut self, density: TickDisplay) -> &mut Self {
-        // Round the canvas height to the nearest multiple using integer division
-        match density {
-            TickDisplay::None => {}
-            TickDisplay::Sparse => {
-                // Round to the nearest 16
-                self.height = if self.height < 16 {
-                    16
-                } else {
-                    ((self.height + 8) / 16) * 16
-                }
+builder! {
+    impl ScaleBuilder {
+        fn normalized(&mut self, enabled: bool) => with_normalized {
+            self.normalized = enabled;
+            self
+        }
+
+        fn y_padding(&mut self, fraction: f32) => with_y_padding {
+            self.y_padding = fraction;
+            self.pad_y_ranges();
+            self
+        }
+    }
+}
+
+builder! {
+    impl SamplingBuilder {
+        fn downsample(&mut self, enabled: bool) => with_downsample {
+            self.downsample = enabled;
+            self
+        }
+
+        fn jump_threshold(&mut self, fraction: f32) => with_jump_threshold {
+            self.jump_threshold = fraction;
+            self
+        }
+
+        fn samples_per_column(&mut self, n: u32) => with_samples_per_column {
+            self.samples_per_column = n.max(1);
+            self.resample();
+            self
+        }
+
+        fn envelope(&mut self, enabled: bool) => with_envelope {
+            self.envelope = enabled;
+            self
+        }
+    }
+}
+
+builder! {
+    impl TextBuilder {
+        fn caption(&mut self, text: &str) => with_caption {
+            self.caption = Some(text.to_string());
+            self
+        }
+
+        fn footer(&mut self, text: &str) => with_footer {
+            self.footer = Some(text.to_string());
+            self
+        }
+
+        fn x_title(&mut self, text: &str) => with_x_title {
+            self.x_title = Some(text.to_string());
+            self
+        }
+
+        fn y_title(&mut self, text: &str) => with_y_title {
+            self.y_title = Some(text.to_string());
+            self
+        }
+
+        fn legend(&mut self, enabled: bool) => with_legend {
+            self.legend = enabled;
+            self
+        }
+
+        fn with_stats(&mut self, enabled: bool) => with_stats_shown {
+            self.stats = enabled;
+            self
+        }
+
+        fn colorbar(&mut self, map: Colormap, min: f32, max: f32) => with_colorbar {
+            self.colorbar = Some((map, min, max));
+            self
+        }
+    }
+}
+
+builder! {
+    impl SeriesBuilder {
+        fn series_name(&mut self, name: &str) => with_series_name {
+            if let Some(series) = self.series.last_mut() {
+                series.name = Some(name.to_string());
             }
-            TickDisplay::Dense => {
-                // Round to the nearest 8
-                self.height = if self.height < 8 {
-                    8
-                } else {
-                    ((self.height + 4) / 8) * 8
+            self
+        }
+
+        fn z_order(&mut self, z: i32) => with_z_order {
+            if let Some(series) = self.shapes.last_mut() {
+                series.z = z;
+            }
+            self
+        }
+
+        fn density(&mut self, enabled: bool) => with_density {
+            if let Some(series) = self.shapes.last_mut() {
+                series.density = enabled;
+            }
+            self
+        }
+    }
+}
+
+builder! {
+    impl CanvasBuilder {
+        fn blank_char(&mut self, c: char) => with_blank_char {
+            self.blank_char = c;
+            self
+        }
+    }
+}
+
+builder! {
+    impl TickDisplayBuilder {
+        /// Specifies the density of y-axis tick labels
+        fn y_tick_display(&mut self, density: TickDisplay) => with_y_tick_display {
+            // Round the canvas height to the nearest multiple using integer division
+            let height = self.height;
+            match density {
+                TickDisplay::None => {}
+                TickDisplay::Sparse => {
+                    // Round to the nearest 16
+                    self.height = if self.height < 16 {
+                        16
+                    } else {
+                        ((self.height + 8) / 16) * 16
+                    }
+                }
+                TickDisplay::Dense => {
+                    // Round to the nearest 8
+                    self.height = if self.height < 8 {
+                        8
+                    } else {
+                        ((self.height + 4) / 8) * 8
+                    }
                 }
             }
+            if self.height != height {
+                self.canvas = BrailleCanvas::new(self.width, self.height);
+            }
+            self.y_tick_display = density;
+            self
+        }
+
+        /// Specifies the density of y-axis tick labels without changing the canvas height
+        fn y_tick_display_keep_height(&mut self, density: TickDisplay)
+            => with_y_tick_display_keep_height {
+            self.y_tick_display = density;
+            self
+        }
+
+        fn x_ticks(&mut self, ticks: &[f32]) => with_x_ticks {
+            self.x_ticks = ticks.iter().map(|&value| (value, None)).collect();
+            self.abbreviate_x_ticks = false;
+            self
+        }
+
+        fn y_ticks(&mut self, ticks: &[f32]) => with_y_ticks {
+            self.y_ticks = ticks.iter().map(|&value| (value, None)).collect();
+            self
+        }
+
+        fn x_ticks_labeled(&mut self, ticks: &[(f32, &str)]) => with_x_ticks_labeled {
+            self.x_ticks = ticks
+                .iter()
+                .map(|&(value, label)| (value, Some(label.to_owned())))
+                .collect();
+            self.abbreviate_x_ticks = false;
+            self
+        }
+
+        fn x_categories(&mut self, labels: &[&str]) => with_x_categories {
+            self.x_ticks = labels
+                .iter()
+                .enumerate()
+                .map(|(i, &label)| (i as f32, Some(label.to_owned())))
+                .collect();
+            self.abbreviate_x_ticks = true;
+            self
+        }
+
+        fn y_ticks_labeled(&mut self, ticks: &[(f32, &str)]) => with_y_ticks_labeled {
+            self.y_ticks = ticks
+                .iter()
+                .map(|&(value, label)| (value, Some(label.to_owned())))
+                .collect();
+            self
+        }
+
+        fn minor_ticks(&mut self, n: u32) => with_minor_ticks {
+            self.minor_ticks = n;
+            self
         }
-        self.y_tick_display = density;
-        self
     }
 }
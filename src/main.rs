@@ -1,70 +1,450 @@
+use rgb::RGB8;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
 use std::process::exit;
+use std::str::FromStr;
 use structopt::StructOpt;
-use textplots::{Chart, Plot, Shape};
+use textplots::spec::{parse_color, DisplayConfig};
+use textplots::utils::sparkline;
+use textplots::{Chart, ColorPlot, Plot, SeriesBuilder, Shape, TextBuilder};
 
 #[derive(StructOpt)]
 struct Opt {
-    /// Formula to plot
+    /// Formulas to plot, each as its own series.
     #[structopt(name = "FORMULA")]
-    formula: String,
-    /// X-axis start value.
-    #[structopt(long, default_value = "-10.0")]
-    xmin: f32,
-    /// X-axis end value.
-    #[structopt(long, default_value = "10.0")]
-    xmax: f32,
+    formulas: Vec<String>,
+    /// X-axis start value, -10 for formulas and the smallest x for data by default.
+    #[structopt(long)]
+    xmin: Option<f32>,
+    /// X-axis end value, 10 for formulas and the largest x for data by default.
+    #[structopt(long)]
+    xmax: Option<f32>,
     /// Y-axis start value.
     #[structopt(long)]
     ymin: Option<f32>,
     /// X-axis end value.
     #[structopt(long)]
     ymax: Option<f32>,
-    /// Canvas width in points.
-    #[structopt(short, long, default_value = "180")]
-    width: u32,
-    /// Canvas height in points.
-    #[structopt(short, long, default_value = "60")]
-    height: u32,
+    /// Canvas width in points, 180 by default.
+    #[structopt(short, long)]
+    width: Option<u32>,
+    /// Canvas height in points, 60 by default.
+    #[structopt(short, long)]
+    height: Option<u32>,
+    /// Title printed above the chart, instead of the formula.
+    #[structopt(long)]
+    title: Option<String>,
+    /// Title of the x-axis.
+    #[structopt(long)]
+    xlabel: Option<String>,
+    /// Title of the y-axis.
+    #[structopt(long)]
+    ylabel: Option<String>,
+    /// Names of the plotted series in order, listed in a legend under the chart.
+    #[structopt(long, name = "NAME", number_of_values = 1)]
+    legend: Vec<String>,
+    /// Colors of the plotted series in order, as `#rrggbb`, `#rgb`, or a name like `red`.
+    #[structopt(long, parse(try_from_str = parse_color_arg), number_of_values = 1)]
+    color: Vec<RGB8>,
+    /// Colors given to the series without one: `default` colors them when there are several,
+    /// `mono` leaves them uncolored, and `colorblind` uses a palette safe for color blindness.
+    #[structopt(long, possible_values = &["default", "mono", "colorblind"])]
+    theme: Option<Theme>,
+    /// Prints the chart without colors, even when the series have one.
+    #[structopt(long)]
+    no_color: bool,
+    /// Configuration file with the defaults of the options, instead of
+    /// `~/.config/textplots/config.toml`.
+    #[structopt(long, parse(from_os_str))]
+    config: Option<PathBuf>,
+    /// Display preferences read from the configuration file.
+    #[structopt(skip)]
+    display: DisplayConfig,
+    #[structopt(subcommand)]
+    command: Option<Command>,
 }
 
-fn main() {
-    let opt = Opt::from_args();
-
-    let res = opt
-        .formula
-        .parse()
-        .and_then(|expr: meval::Expr| expr.bind("x"));
-    let func = match res {
-        Ok(func) => func,
-        Err(err) => {
-            // if there was an error with parsing
-            // or binding "x", exit with error
+/// Colors given to the series without one.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Theme {
+    Default,
+    Mono,
+    Colorblind,
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Theme::Default),
+            "mono" => Ok(Theme::Mono),
+            "colorblind" => Ok(Theme::Colorblind),
+            _ => Err(format!("unknown theme: {}", s)),
+        }
+    }
+}
+
+/// Defaults of the options, read from the configuration file.
+///
+/// ```toml
+/// width = 120
+/// height = 40
+/// theme = "colorblind"
+/// y_label_format = "si_prefix"
+/// border = "box"
+/// ```
+///
+/// Besides the canvas size and the theme, any of the display preferences
+/// of `textplots::spec::DisplayConfig` can be given.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Config {
+    width: Option<u32>,
+    height: Option<u32>,
+    theme: Option<Theme>,
+    #[serde(flatten)]
+    display: DisplayConfig,
+}
+
+impl Opt {
+    /// Fills the options not given on the command line from the configuration file.
+    fn apply_config(&mut self, config: Config) {
+        self.width = self.width.or(config.width);
+        self.height = self.height.or(config.height);
+        self.theme = self.theme.or(config.theme);
+        self.display = config.display;
+    }
+}
+
+/// Reads the configuration file given on the command line, or the default one if it exists,
+/// exiting with an error if it can't be read or parsed.
+fn read_config(path: Option<&PathBuf>) -> Config {
+    let path = match path {
+        Some(path) => path.clone(),
+        None => match default_config_path() {
+            Some(path) if path.exists() => path,
+            _ => return Config::default(),
+        },
+    };
 
-            eprintln!("{}", err);
+    let res = fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|text| toml::from_str(&text).map_err(|err| err.to_string()));
+    match res {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}: {}", path.display(), err);
             exit(1);
         }
+    }
+}
+
+/// Returns the path of the default configuration file, within `$XDG_CONFIG_HOME`
+/// or `~/.config`.
+fn default_config_path() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
     };
+    Some(dir.join("textplots").join("config.toml"))
+}
+
+/// Colors of the series with the default theme.
+const DEFAULT_PALETTE: [RGB8; 6] = [
+    RGB8::new(31, 119, 180),
+    RGB8::new(255, 127, 14),
+    RGB8::new(44, 160, 44),
+    RGB8::new(214, 39, 40),
+    RGB8::new(148, 103, 189),
+    RGB8::new(23, 190, 207),
+];
+
+/// Colors of the series with the colorblind theme, from the Okabe-Ito palette.
+const COLORBLIND_PALETTE: [RGB8; 7] = [
+    RGB8::new(230, 159, 0),
+    RGB8::new(86, 180, 233),
+    RGB8::new(0, 158, 115),
+    RGB8::new(240, 228, 66),
+    RGB8::new(0, 114, 178),
+    RGB8::new(213, 94, 0),
+    RGB8::new(204, 121, 167),
+];
+
+#[derive(StructOpt)]
+enum Command {
+    /// Prints a single-line sparkline of the numbers read from the standard input.
+    Spark {
+        /// Prints the smallest value before the sparkline and the largest one after it.
+        #[structopt(short, long)]
+        annotate: bool,
+    },
+    /// Plots the comma or whitespace separated values read from the standard input.
+    ///
+    /// Lines with a single value are plotted against their index, and lines with more
+    /// against the first value, one series per column. A header line is skipped.
+    Data {
+        /// How the points are drawn.
+        #[structopt(long, default_value = "line", possible_values = &["line", "points", "steps", "bars"])]
+        mode: Mode,
+    },
+}
+
+/// How the points of data are drawn.
+#[derive(Clone, Copy)]
+enum Mode {
+    Line,
+    Points,
+    Steps,
+    Bars,
+}
+
+impl FromStr for Mode {
+    type Err = String;
 
-    // check for invalid ymin/ymax
-    if (opt.ymax.is_none() && opt.ymin.is_some()) || (opt.ymax.is_some() && opt.ymin.is_none()) {
-        eprintln!("both ymin and ymax must be specified");
-        exit(2);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "line" => Ok(Mode::Line),
+            "points" => Ok(Mode::Points),
+            "steps" => Ok(Mode::Steps),
+            "bars" => Ok(Mode::Bars),
+            _ => Err(format!("unknown mode: {}", s)),
+        }
     }
+}
 
-    println!("y = {}", opt.formula);
-    let mut chart = if opt.ymin.is_none() {
-        Chart::new(opt.width, opt.height, opt.xmin, opt.xmax)
-    } else {
-        Chart::new_with_y_range(
-            opt.width,
-            opt.height,
-            opt.xmin,
-            opt.xmax,
-            opt.ymin.unwrap(),
-            opt.ymax.unwrap(),
-        )
+/// Parses a color given on the command line.
+fn parse_color_arg(text: &str) -> Result<RGB8, String> {
+    parse_color(text).ok_or_else(|| format!("unknown color: {}", text))
+}
+
+fn main() {
+    let mut opt = Opt::from_args();
+    let config = read_config(opt.config.as_ref());
+    opt.apply_config(config);
+
+    if opt.no_color {
+        colored::control::set_override(false);
+    }
+
+    match (&opt.command, opt.formulas.is_empty()) {
+        (Some(Command::Spark { annotate }), _) => spark(*annotate),
+        (Some(Command::Data { mode }), _) => plot_data(&opt, *mode),
+        (None, false) => plot_formulas(&opt),
+        (None, true) => {
+            eprintln!("either a formula or a subcommand must be specified");
+            exit(2);
+        }
+    }
+}
+
+/// Plots the formulas of `x`, each as its own series.
+fn plot_formulas(opt: &Opt) {
+    let shapes: Vec<Shape> = opt
+        .formulas
+        .iter()
+        .map(|formula| {
+            let res = formula.parse().and_then(|expr: meval::Expr| expr.bind("x"));
+            match res {
+                Ok(func) => Shape::Continuous(Box::new(move |x| func(x.into()) as f32)),
+                Err(err) => {
+                    // if there was an error with parsing
+                    // or binding "x", exit with error
+
+                    eprintln!("{}", err);
+                    exit(1);
+                }
+            }
+        })
+        .collect();
+
+    let title = format!("y = {}", opt.formulas.join(", y = "));
+    let xmin = opt.xmin.unwrap_or(-10.0);
+    let xmax = opt.xmax.unwrap_or(10.0);
+    plot_shapes(opt, &shapes, xmin, xmax, Some(&title));
+}
+
+/// Plots the data read from the standard input.
+fn plot_data(opt: &Opt, mode: Mode) {
+    let series = read_series();
+    let points = series.iter().flatten().map(|&(x, _)| x);
+    let xmin = opt
+        .xmin
+        .unwrap_or_else(|| points.clone().fold(f32::INFINITY, f32::min));
+    let xmax = opt
+        .xmax
+        .unwrap_or_else(|| points.fold(f32::NEG_INFINITY, f32::max));
+    if xmin >= xmax {
+        eprintln!("the data should span a range of x");
+        exit(1);
+    }
+
+    let shapes: Vec<Shape> = series
+        .iter()
+        .map(|points| match mode {
+            Mode::Line => Shape::Lines(points),
+            Mode::Points => Shape::Points(points),
+            Mode::Steps => Shape::Steps(points),
+            Mode::Bars => Shape::Bars(points),
+        })
+        .collect();
+    plot_shapes(opt, &shapes, xmin, xmax, None);
+}
+
+/// Displays the shapes as series of a chart, applying the options given on the command line.
+fn plot_shapes(opt: &Opt, shapes: &[Shape], xmin: f32, xmax: f32, default_title: Option<&str>) {
+    let width = opt.width.unwrap_or(180);
+    let height = opt.height.unwrap_or(60);
+    let mut chart = match (opt.ymin, opt.ymax) {
+        (None, None) => Chart::new(width, height, xmin, xmax),
+        (Some(ymin), Some(ymax)) => Chart::new_with_y_range(width, height, xmin, xmax, ymin, ymax),
+        // check for invalid ymin/ymax
+        _ => {
+            eprintln!("both ymin and ymax must be specified");
+            exit(2);
+        }
     };
+    let chart = opt.display.apply(&mut chart);
+    let mut chart = annotate(chart, opt, default_title);
+    for (k, shape) in shapes.iter().enumerate() {
+        chart = match series_color(opt, k, shapes.len()) {
+            Some(color) => chart.linecolorplot(shape, color),
+            None => chart.lineplot(shape),
+        };
+        if let Some(name) = opt.legend.get(k) {
+            chart = chart.series_name(name);
+        }
+    }
+    if !opt.legend.is_empty() {
+        chart = chart.legend(true);
+    }
+    chart.display();
+}
+
+/// Returns the color of the `k`-th of the series, either given on the command line
+/// or picked by the theme.
+fn series_color(opt: &Opt, k: usize, series: usize) -> Option<RGB8> {
+    if let Some(&color) = opt.color.get(k) {
+        return Some(color);
+    }
+
+    match opt.theme.unwrap_or(Theme::Default) {
+        // a single series is clear enough without a color
+        Theme::Default if series > 1 => Some(DEFAULT_PALETTE[k % DEFAULT_PALETTE.len()]),
+        Theme::Default | Theme::Mono => None,
+        Theme::Colorblind => Some(COLORBLIND_PALETTE[k % COLORBLIND_PALETTE.len()]),
+    }
+}
+
+/// Applies the title and axis titles given on the command line, falling back to
+/// the default title.
+fn annotate<'a>(
+    chart: &'a mut Chart<'a>,
+    opt: &Opt,
+    default_title: Option<&str>,
+) -> &'a mut Chart<'a> {
+    let mut chart = chart;
+    if let Some(title) = opt.title.as_deref().or(default_title) {
+        chart = chart.caption(title);
+    }
+    if let Some(xlabel) = &opt.xlabel {
+        chart = chart.x_title(xlabel);
+    }
+    if let Some(ylabel) = &opt.ylabel {
+        chart = chart.y_title(ylabel);
+    }
     chart
-        .lineplot(&Shape::Continuous(Box::new(|x| func(x.into()) as f32)))
-        .display();
+}
+
+/// Prints the sparkline of the numbers read from the standard input.
+fn spark(annotate: bool) {
+    let values = read_numbers();
+    let line = sparkline(&values);
+
+    if annotate {
+        let finite = values.iter().copied().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f32::INFINITY, f32::min);
+        let max = finite.fold(f32::NEG_INFINITY, f32::max);
+        println!("{} {} {}", min, line, max);
+    } else {
+        println!("{}", line);
+    }
+}
+
+/// Reads the numbers separated by whitespace or commas from the standard input,
+/// exiting with an error if anything else is found.
+fn read_numbers() -> Vec<f32> {
+    read_input()
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(parse_number)
+        .collect()
+}
+
+/// Reads the series of points from the lines of the standard input, one series per column
+/// after the first one, or a single series of the values against their index if there's
+/// only one column.
+fn read_series() -> Vec<Vec<(f32, f32)>> {
+    let input = read_input();
+    let rows: Vec<Vec<&str>> = input
+        .lines()
+        .map(|line| {
+            line.split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|token| !token.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|tokens| !tokens.is_empty())
+        .collect();
+
+    // skip the header, if any
+    let header = rows
+        .first()
+        .is_some_and(|tokens| tokens.iter().any(|token| token.parse::<f32>().is_err()));
+    let rows = &rows[header as usize..];
+
+    let columns = rows.first().map_or(0, |tokens| tokens.len());
+    let mut series = vec![Vec::with_capacity(rows.len()); columns.saturating_sub(1).max(1)];
+    for (i, tokens) in rows.iter().enumerate() {
+        if tokens.len() != columns {
+            eprintln!("expected {} values on line: {}", columns, tokens.join(" "));
+            exit(1);
+        }
+        let values: Vec<f32> = tokens.iter().map(|token| parse_number(token)).collect();
+        match values.split_first() {
+            Some((&y, [])) => series[0].push((i as f32, y)),
+            Some((&x, ys)) => {
+                for (points, &y) in series.iter_mut().zip(ys) {
+                    points.push((x, y));
+                }
+            }
+            None => {}
+        }
+    }
+
+    series
+}
+
+/// Parses a number, exiting with an error if it's not one.
+fn parse_number(token: &str) -> f32 {
+    match token.parse() {
+        Ok(value) => value,
+        Err(_) => {
+            eprintln!("not a number: {}", token);
+            exit(1);
+        }
+    }
+}
+
+/// Reads the whole standard input, exiting with an error if it can't be read.
+fn read_input() -> String {
+    let mut input = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut input) {
+        eprintln!("{}", err);
+        exit(1);
+    }
+    input
 }
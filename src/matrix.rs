@@ -0,0 +1,204 @@
+//! Grayscale matrices drawn with a braille dot for every value.
+//!
+//! ```rust
+//! use textplots::matrix::Matrix;
+//!
+//! let glider: [&[f32]; 3] = [&[0.0, 1.0, 0.0], &[0.0, 0.0, 1.0], &[1.0, 1.0, 1.0]];
+//! assert_eq!(Matrix::new(&glider).threshold(0.5).to_string(), "⠬⠆\n");
+//!
+//! let spectrogram: Vec<Vec<f32>> = (0..32)
+//!     .map(|y| (0..80).map(|x| ((x as f32 / 8.0).sin() * (y as f32 / 6.0).cos()).abs()).collect())
+//!     .collect();
+//! let rows: Vec<&[f32]> = spectrogram.iter().map(|row| row.as_slice()).collect();
+//! Matrix::new(&rows).dither().display();
+//!
+//! // one character per value, its number of dots following the value
+//! let heat: [&[f32]; 2] = [&[0.0, 0.25, 0.5, 0.75, 1.0], &[1.0, 0.75, 0.5, 0.25, 0.0]];
+//! assert_eq!(Matrix::new(&heat).ordered().cells().to_string(), "⠀⢁⢕⢷⣿\n⣿⢷⢕⢁⠀\n");
+//! ```
+
+use drawille::Canvas as BrailleCanvas;
+use std::fmt::{Display, Formatter, Result};
+
+/// How values are turned into dots.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shading {
+    /// Values at or above the threshold are drawn as dots.
+    Threshold(f32),
+    /// Values are scaled to the range and diffused into dots, the density following the value.
+    Dither,
+    /// Values are scaled to the range and compared with a threshold that varies over the
+    /// dots of a character, so a character covering a single value has 0 to 8 dots.
+    Ordered,
+}
+
+/// Order in which dots are set by `Shading::Ordered`, spreading them evenly over the 2×4
+/// dots of a character, indexed by row and then column within the character.
+const ORDER: [[usize; 2]; 4] = [[0, 6], [4, 2], [3, 5], [7, 1]];
+
+/// Matrix of values drawn at full canvas resolution, the first row being the top one.
+///
+/// Each value covers a single dot, so every character shows a block of 2×4 values.
+/// Values that are not finite are never drawn.
+pub struct Matrix<'a> {
+    rows: &'a [&'a [f32]],
+    shading: Shading,
+    /// Values shown as no dots and as all dots when dithering, the extremes of the values if `None`.
+    range: Option<(f32, f32)>,
+    /// Whether each value covers a whole character instead of a single dot.
+    cells: bool,
+}
+
+impl<'a> Matrix<'a> {
+    /// Creates a new `Matrix` from the rows of values, using a threshold of `0.5` by default.
+    pub fn new(rows: &'a [&'a [f32]]) -> Self {
+        Self {
+            rows,
+            shading: Shading::Threshold(0.5),
+            range: None,
+            cells: false,
+        }
+    }
+
+    /// Draws the values at or above the threshold.
+    pub fn threshold(&mut self, threshold: f32) -> &mut Self {
+        self.shading = Shading::Threshold(threshold);
+        self
+    }
+
+    /// Draws the values with error diffusion dithering, so the density of dots follows the values.
+    pub fn dither(&mut self) -> &mut Self {
+        self.shading = Shading::Dither;
+        self
+    }
+
+    /// Draws the values with ordered dithering, the number of dots in a character encoding
+    /// the value, which reads as shades of gray on terminals without colors.
+    pub fn ordered(&mut self) -> &mut Self {
+        self.shading = Shading::Ordered;
+        self
+    }
+
+    /// Makes each value cover a whole character of 2×4 dots, for heatmaps of small matrices.
+    pub fn cells(&mut self) -> &mut Self {
+        self.cells = true;
+        self
+    }
+
+    /// Specifies the values shown as no dots and as all dots when dithering.
+    pub fn range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Prints the matrix.
+    pub fn display(&mut self) {
+        println!("{}", self);
+    }
+
+    /// Returns the values shown as no dots and as all dots when dithering.
+    fn bounds(&self) -> (f32, f32) {
+        self.range.unwrap_or_else(|| {
+            self.rows
+                .iter()
+                .flat_map(|row| row.iter().copied())
+                .filter(|value| value.is_finite())
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                })
+        })
+    }
+
+    /// Returns the value drawn at the dot, if any.
+    fn value(&self, x: usize, y: usize) -> Option<f32> {
+        let (x, y) = if self.cells { (x / 2, y / 4) } else { (x, y) };
+        self.rows.get(y).and_then(|row| row.get(x)).copied()
+    }
+
+    /// Draws the values onto a new canvas.
+    fn canvas(&self) -> BrailleCanvas {
+        let (sx, sy) = if self.cells { (2, 4) } else { (1, 1) };
+        let width = self.rows.iter().map(|row| row.len()).max().unwrap_or(0) * sx;
+        let height = self.rows.len() * sy;
+        // the canvas spans the points up to its size inclusive, so leave out the last one
+        let mut canvas = BrailleCanvas::new(
+            width.saturating_sub(1) as u32,
+            height.saturating_sub(1) as u32,
+        );
+
+        match self.shading {
+            Shading::Threshold(threshold) => {
+                for y in 0..height {
+                    for x in 0..width {
+                        if self.value(x, y).is_some_and(|value| value >= threshold) {
+                            canvas.set(x as u32, y as u32);
+                        }
+                    }
+                }
+            }
+            Shading::Ordered => {
+                let (min, max) = self.bounds();
+                let span = if max > min { max - min } else { 1.0 };
+
+                for y in 0..height {
+                    for x in 0..width {
+                        let value = match self.value(x, y) {
+                            Some(value) if value.is_finite() => {
+                                ((value - min) / span).clamp(0.0, 1.0)
+                            }
+                            _ => continue,
+                        };
+                        if (value * 8.0).round() as usize > ORDER[y % 4][x % 2] {
+                            canvas.set(x as u32, y as u32);
+                        }
+                    }
+                }
+            }
+            Shading::Dither => {
+                let (min, max) = self.bounds();
+                let span = if max > min { max - min } else { 1.0 };
+
+                // Floyd-Steinberg, carrying the error of the current row into the next one
+                let mut current = vec![0.0; width + 1];
+                let mut next = vec![0.0; width + 1];
+                for y in 0..height {
+                    for x in 0..width {
+                        let value = match self.value(x, y) {
+                            Some(value) if value.is_finite() => {
+                                ((value - min) / span).clamp(0.0, 1.0)
+                            }
+                            _ => continue,
+                        };
+                        let shade = value + current[x];
+                        let error = if shade >= 0.5 {
+                            canvas.set(x as u32, y as u32);
+                            shade - 1.0
+                        } else {
+                            shade
+                        };
+
+                        current[x + 1] += error * 7.0 / 16.0;
+                        if x > 0 {
+                            next[x - 1] += error * 3.0 / 16.0;
+                        }
+                        next[x] += error * 5.0 / 16.0;
+                        next[x + 1] += error / 16.0;
+                    }
+                    current = std::mem::replace(&mut next, vec![0.0; width + 1]);
+                }
+            }
+        }
+
+        canvas
+    }
+}
+
+impl Display for Matrix<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for row in self.canvas().rows() {
+            writeln!(f, "{}", row.replace(' ', "\u{2800}"))?;
+        }
+
+        Ok(())
+    }
+}
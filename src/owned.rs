@@ -0,0 +1,182 @@
+//! Charts owning their data, which can be built in one place and displayed in another,
+//! like a function returning a chart or a struct keeping one around.
+//!
+//! ```rust
+//! use textplots::owned::OwnedChart;
+//! use textplots::LabelBuilder;
+//!
+//! fn build() -> OwnedChart {
+//!     let points: Vec<(f32, f32)> = (0..50).map(|i| (i as f32, (i % 7) as f32)).collect();
+//!
+//!     let mut chart = OwnedChart::new(120, 60, 0.0, 50.0);
+//!     chart
+//!         .push_line(points)
+//!         .name("load")
+//!         .push_function(|x| 3.0 + (x / 5.0).sin())
+//!         .configure(|chart| chart.x_label_format(textplots::LabelFormat::Auto));
+//!     chart
+//! }
+//!
+//! build().display();
+//! ```
+
+use crate::{Chart, ColorPlot, Plot, SeriesBuilder, Shape};
+use rgb::RGB8;
+
+/// Builds the configuration of a chart on top of its shapes, see [`OwnedChart::configure`].
+type Configure = Box<dyn for<'c> Fn(&'c mut Chart<'c>) -> &'c mut Chart<'c>>;
+
+/// Plotted data owned by the chart.
+enum OwnedData {
+    Function(Box<dyn Fn(f32) -> f32>),
+    Lines(Vec<(f32, f32)>),
+    Points(Vec<(f32, f32)>),
+    Steps(Vec<(f32, f32)>),
+    Bars(Vec<(f32, f32)>),
+}
+
+/// A series of the chart, with the optional color and name it is plotted with.
+struct OwnedSeries {
+    data: OwnedData,
+    color: Option<RGB8>,
+    name: Option<String>,
+}
+
+/// A chart owning the data of its series. Every series is plotted like with `Plot::lineplot`,
+/// or `ColorPlot::linecolorplot` when given a color, into a `Chart` built on every render.
+pub struct OwnedChart {
+    width: u32,
+    height: u32,
+    xmin: f32,
+    xmax: f32,
+    y_range: Option<(f32, f32)>,
+    series: Vec<OwnedSeries>,
+    configure: Option<Configure>,
+}
+
+impl OwnedChart {
+    /// Creates a new `OwnedChart` without series, with the same arguments as `Chart::new`.
+    /// Sizes smaller than the minimum are raised to it when rendering.
+    pub fn new(width: u32, height: u32, xmin: f32, xmax: f32) -> Self {
+        Self {
+            width,
+            height,
+            xmin,
+            xmax,
+            y_range: None,
+            series: Vec::new(),
+            configure: None,
+        }
+    }
+
+    /// Specifies a fixed range of the y-axis instead of calculating it automatically.
+    pub fn y_range(&mut self, ymin: f32, ymax: f32) -> &mut Self {
+        self.y_range = Some((ymin, ymax));
+        self
+    }
+
+    /// Adds a function plotted like `Shape::Continuous`.
+    pub fn push_function<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(f32) -> f32 + 'static,
+    {
+        self.push(OwnedData::Function(Box::new(f)))
+    }
+
+    /// Adds points connected with lines, plotted like `Shape::Lines`.
+    pub fn push_line(&mut self, points: Vec<(f32, f32)>) -> &mut Self {
+        self.push(OwnedData::Lines(points))
+    }
+
+    /// Adds points, plotted like `Shape::Points`.
+    pub fn push_points(&mut self, points: Vec<(f32, f32)>) -> &mut Self {
+        self.push(OwnedData::Points(points))
+    }
+
+    /// Adds points connected with steps, plotted like `Shape::Steps`.
+    pub fn push_steps(&mut self, points: Vec<(f32, f32)>) -> &mut Self {
+        self.push(OwnedData::Steps(points))
+    }
+
+    /// Adds bars, plotted like `Shape::Bars`.
+    pub fn push_bars(&mut self, points: Vec<(f32, f32)>) -> &mut Self {
+        self.push(OwnedData::Bars(points))
+    }
+
+    fn push(&mut self, data: OwnedData) -> &mut Self {
+        self.series.push(OwnedSeries {
+            data,
+            color: None,
+            name: None,
+        });
+        self
+    }
+
+    /// Specifies the color of the most recently added series.
+    pub fn color(&mut self, color: RGB8) -> &mut Self {
+        if let Some(series) = self.series.last_mut() {
+            series.color = Some(color);
+        }
+        self
+    }
+
+    /// Specifies the name of the most recently added series, see `SeriesBuilder::series_name`.
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        if let Some(series) = self.series.last_mut() {
+            series.name = Some(name.to_string());
+        }
+        self
+    }
+
+    /// Specifies how the chart is configured once the series are plotted, with any of the
+    /// builder methods of `Chart`, like labels, ticks or styles.
+    pub fn configure<F>(&mut self, f: F) -> &mut Self
+    where
+        F: for<'c> Fn(&'c mut Chart<'c>) -> &'c mut Chart<'c> + 'static,
+    {
+        self.configure = Some(Box::new(f));
+        self
+    }
+
+    /// Draws the chart and returns it as a string, see `Chart::render`.
+    pub fn render(&self) -> String {
+        let shapes: Vec<Shape> = self
+            .series
+            .iter()
+            .map(|series| match &series.data {
+                OwnedData::Function(f) => Shape::Continuous(Box::new(f)),
+                OwnedData::Lines(points) => Shape::Lines(points),
+                OwnedData::Points(points) => Shape::Points(points),
+                OwnedData::Steps(points) => Shape::Steps(points),
+                OwnedData::Bars(points) => Shape::Bars(points),
+            })
+            .collect();
+
+        let mut chart = match self.y_range {
+            Some((ymin, ymax)) => {
+                Chart::new_with_y_range(self.width, self.height, self.xmin, self.xmax, ymin, ymax)
+            }
+            None => Chart::new(self.width, self.height, self.xmin, self.xmax),
+        };
+        let mut chart = &mut chart;
+        for (shape, series) in shapes.iter().zip(&self.series) {
+            chart = match series.color {
+                Some(color) => chart.linecolorplot(shape, color),
+                None => chart.lineplot(shape),
+            };
+            if let Some(name) = &series.name {
+                chart = chart.series_name(name);
+            }
+        }
+        if let Some(configure) = &self.configure {
+            chart = configure(chart);
+        }
+
+        chart.render()
+    }
+
+    /// Prints the chart, see `Chart::display`.
+    pub fn display(&self) {
+        println!("{}", self.render());
+    }
+}
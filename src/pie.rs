@@ -0,0 +1,211 @@
+//! Pie and donut charts showing the composition of a whole.
+//!
+//! ```rust
+//! use textplots::pie::PieChart;
+//!
+//! PieChart::new(40)
+//!     .slice("rust", 60.0)
+//!     .slice("python", 25.0)
+//!     .slice("other", 15.0)
+//!     .donut(0.5)
+//!     .display();
+//! ```
+
+use crate::rgb_to_pixelcolor;
+use colored::Colorize;
+use drawille::Canvas as BrailleCanvas;
+use rgb::RGB8;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::fmt::{Display, Formatter, Result};
+
+/// Part of the whole shown by a pie chart.
+struct Slice {
+    label: String,
+    value: f32,
+    color: Option<RGB8>,
+}
+
+/// Pie chart drawn with braille arcs, followed by a legend with the share of every slice.
+///
+/// Slices start at 12 o'clock and go clockwise. Colored slices are filled with their color,
+/// others are outlined and numbered, with the numbers repeated in the legend.
+pub struct PieChart {
+    /// Diameter of the pie in canvas points.
+    size: u32,
+    /// Radius of the hole as a fraction of the pie radius, zero for a pie.
+    hole: f32,
+    slices: Vec<Slice>,
+}
+
+impl PieChart {
+    /// Creates a new `PieChart` with the given diameter in canvas points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is less than 8.
+    pub fn new(size: u32) -> Self {
+        if size < 8 {
+            panic!("size should be at least 8");
+        }
+
+        Self {
+            size,
+            hole: 0.0,
+            slices: Vec::new(),
+        }
+    }
+
+    /// Adds a slice with the given value, its share being relative to the sum of all values.
+    /// Values that are negative or not finite are ignored.
+    pub fn slice(&mut self, label: &str, value: f32) -> &mut Self {
+        self.push(label, value, None)
+    }
+
+    /// Same as `slice`, but the slice is filled with the specified color.
+    pub fn color_slice(&mut self, label: &str, value: f32, color: RGB8) -> &mut Self {
+        self.push(label, value, Some(color))
+    }
+
+    /// Cuts a hole of the given fraction of the radius out of the middle, turning the pie into a donut.
+    pub fn donut(&mut self, hole: f32) -> &mut Self {
+        self.hole = hole.clamp(0.0, 0.9);
+        self
+    }
+
+    /// Prints the chart.
+    pub fn display(&mut self) {
+        println!("{}", self);
+    }
+
+    fn push(&mut self, label: &str, value: f32, color: Option<RGB8>) -> &mut Self {
+        if value.is_finite() && value >= 0.0 {
+            self.slices.push(Slice {
+                label: label.to_string(),
+                value,
+                color,
+            });
+        }
+        self
+    }
+
+    /// Returns the angle every slice ends at, clockwise from 12 o'clock.
+    fn ends(&self) -> Vec<f32> {
+        let total: f32 = self.slices.iter().map(|slice| slice.value).sum();
+        let mut end = 0.0;
+
+        self.slices
+            .iter()
+            .map(|slice| {
+                if total > 0.0 {
+                    end += slice.value / total * TAU;
+                }
+                end
+            })
+            .collect()
+    }
+
+    /// Draws the slices onto a new canvas.
+    fn canvas(&self) -> BrailleCanvas {
+        let mut canvas = BrailleCanvas::new(self.size, self.size);
+        let radius = self.size as f32 / 2.0;
+        let center = (self.size - 1) as f32 / 2.0;
+        let inner = radius * self.hole;
+        let ends = self.ends();
+
+        let slice_at = |angle: f32| ends.iter().position(|&end| angle < end);
+
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let (dx, dy) = (x as f32 - center, y as f32 - center);
+                let r = dx.hypot(dy);
+                if r > radius || r < inner {
+                    continue;
+                }
+
+                // clockwise from 12 o'clock, as the canvas y axis points down
+                let angle = (dy.atan2(dx) + FRAC_PI_2).rem_euclid(TAU);
+                let k = match slice_at(angle) {
+                    Some(k) => k,
+                    None => continue,
+                };
+
+                // outline the rims, and the boundaries between slices half a point wide
+                let rim = r > radius - 1.0 || (inner > 0.0 && r < inner + 1.0);
+                let boundary = ends.iter().any(|&end| {
+                    let delta = (angle - end + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+                    (delta * r).abs() < 0.5
+                });
+
+                match self.slices[k].color {
+                    Some(color) => canvas.set_colored(x, y, rgb_to_pixelcolor(&color)),
+                    None if rim || (boundary && ends.len() > 1) => canvas.set(x, y),
+                    None => {}
+                }
+            }
+        }
+
+        // number the uncolored slices in their middle
+        let mut start = 0.0;
+        for (k, (slice, &end)) in self.slices.iter().zip(&ends).enumerate() {
+            if slice.color.is_none() && end > start {
+                let angle = (start + end) / 2.0 - FRAC_PI_2;
+                let r = (radius + inner) / 2.0;
+                let x = center + r * angle.cos();
+                let y = center + r * angle.sin();
+                canvas.text(x.round() as u32, y.round() as u32, 2, &(k + 1).to_string());
+            }
+            start = end;
+        }
+
+        canvas
+    }
+
+    /// Returns the legend line of every slice.
+    fn legend(&self) -> Vec<String> {
+        let total: f32 = self.slices.iter().map(|slice| slice.value).sum();
+
+        self.slices
+            .iter()
+            .enumerate()
+            .map(|(k, slice)| {
+                let marker = match slice.color {
+                    Some(color) => "⣿".truecolor(color.r, color.g, color.b).to_string(),
+                    None => (k + 1).to_string(),
+                };
+                let share = if total > 0.0 {
+                    slice.value / total * 100.0
+                } else {
+                    0.0
+                };
+                format!("{} {} {:.1}%", marker, slice.label, share)
+            })
+            .collect()
+    }
+}
+
+impl Display for PieChart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let rows: Vec<String> = self
+            .canvas()
+            .rows()
+            .iter()
+            .map(|row| row.replace(' ', "\u{2800}"))
+            .collect();
+        let legend = self.legend();
+
+        // place the legend to the right of the pie, vertically centered
+        let offset = rows.len().saturating_sub(legend.len()) / 2;
+        let lines = rows.len().max(legend.len());
+        let blank = "\u{2800}".repeat(rows.first().map_or(0, |row| row.chars().count()));
+
+        for i in 0..lines {
+            let row = rows.get(i).unwrap_or(&blank);
+            match i.checked_sub(offset).and_then(|k| legend.get(k)) {
+                Some(entry) => writeln!(f, "{}  {}", row, entry)?,
+                None => writeln!(f, "{}", row)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+//! Radar (spider) charts comparing several series across named dimensions.
+//!
+//! ```rust
+//! use textplots::radar::RadarChart;
+//! use rgb::RGB8;
+//!
+//! RadarChart::new(60, &["parse", "typecheck", "codegen", "link", "test"])
+//!     .range(0.0, 10.0)
+//!     .color_series("before", &[8.0, 6.0, 9.0, 4.0, 7.0], RGB8::new(220, 0, 0))
+//!     .color_series("after", &[5.0, 6.0, 4.0, 3.0, 6.0], RGB8::new(0, 200, 0))
+//!     .display();
+//! ```
+
+use crate::rgb_to_pixelcolor;
+use colored::Colorize;
+use drawille::Canvas as BrailleCanvas;
+use rgb::RGB8;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::fmt::{Display, Formatter, Result};
+
+/// Canvas points left around the chart for the spoke numbers.
+const MARGIN: f32 = 6.0;
+
+/// Values of a series along every dimension.
+struct RadarSeries {
+    name: String,
+    values: Vec<f32>,
+    color: Option<RGB8>,
+}
+
+/// Radar chart with a spoke for every dimension, starting at 12 o'clock and going clockwise.
+///
+/// The spokes are numbered at their ends, and the numbers are explained in the legend
+/// under the chart, followed by the names of the series.
+pub struct RadarChart {
+    /// Diameter of the chart in canvas points.
+    size: u32,
+    dimensions: Vec<String>,
+    /// Values at the center and at the ends of the spokes, calculated from the series if `None`.
+    range: Option<(f32, f32)>,
+    series: Vec<RadarSeries>,
+}
+
+impl RadarChart {
+    /// Creates a new `RadarChart` with the given diameter in canvas points and dimension names.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is less than 16 or there are less than 3 dimensions.
+    pub fn new(size: u32, dimensions: &[&str]) -> Self {
+        if size < 16 {
+            panic!("size should be at least 16");
+        }
+        if dimensions.len() < 3 {
+            panic!("there should be at least 3 dimensions");
+        }
+
+        Self {
+            size,
+            dimensions: dimensions.iter().map(|name| name.to_string()).collect(),
+            range: None,
+            series: Vec::new(),
+        }
+    }
+
+    /// Specifies the values at the center and at the ends of the spokes. By default the range
+    /// goes from zero to the largest value of all series.
+    pub fn range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Adds a series with a value for every dimension, missing values are taken as the minimum.
+    pub fn series(&mut self, name: &str, values: &[f32]) -> &mut Self {
+        self.push(name, values, None)
+    }
+
+    /// Same as `series`, but the series is drawn using the specified color.
+    pub fn color_series(&mut self, name: &str, values: &[f32], color: RGB8) -> &mut Self {
+        self.push(name, values, Some(color))
+    }
+
+    /// Prints the chart.
+    pub fn display(&mut self) {
+        println!("{}", self);
+    }
+
+    fn push(&mut self, name: &str, values: &[f32], color: Option<RGB8>) -> &mut Self {
+        self.series.push(RadarSeries {
+            name: name.to_string(),
+            values: values.to_vec(),
+            color,
+        });
+        self
+    }
+
+    /// Returns the values at the center and at the ends of the spokes.
+    fn bounds(&self) -> (f32, f32) {
+        self.range.unwrap_or_else(|| {
+            let max = self
+                .series
+                .iter()
+                .flat_map(|series| series.values.iter().copied())
+                .filter(|value| value.is_finite())
+                .fold(0.0, f32::max);
+            (0.0, max)
+        })
+    }
+
+    /// Returns the canvas point at the given distance from the center along the spoke.
+    fn point(&self, spoke: usize, distance: f32) -> (f32, f32) {
+        let center = self.size as f32 / 2.0 + MARGIN;
+        let angle = spoke as f32 / self.dimensions.len() as f32 * TAU - FRAC_PI_2;
+        (
+            center + distance * angle.cos(),
+            center + distance * angle.sin(),
+        )
+    }
+
+    /// Draws the spokes and the series onto a new canvas.
+    fn canvas(&self) -> BrailleCanvas {
+        let extent = self.size + 2 * MARGIN as u32;
+        let mut canvas = BrailleCanvas::new(extent, extent);
+        let radius = self.size as f32 / 2.0;
+        let n = self.dimensions.len();
+
+        // dotted spokes and the outline connecting their ends
+        for spoke in 0..n {
+            let (x1, y1) = self.point(spoke, radius);
+            let (x2, y2) = self.point((spoke + 1) % n, radius);
+            let steps = radius as u32;
+            for step in (0..=steps).step_by(3) {
+                let t = step as f32 / steps as f32;
+                let (x, y) = self.point(spoke, radius * t);
+                canvas.set(x.round() as u32, y.round() as u32);
+                canvas.set(
+                    (x1 + (x2 - x1) * t).round() as u32,
+                    (y1 + (y2 - y1) * t).round() as u32,
+                );
+            }
+        }
+
+        // a polygon through the values of every series
+        let (min, max) = self.bounds();
+        for series in &self.series {
+            let points: Vec<(u32, u32)> = (0..n)
+                .map(|spoke| {
+                    let value = series.values.get(spoke).copied().unwrap_or(min);
+                    let fraction = if max > min && value.is_finite() {
+                        ((value - min) / (max - min)).clamp(0.0, 1.0)
+                    } else {
+                        0.0
+                    };
+                    let (x, y) = self.point(spoke, radius * fraction);
+                    (x.round() as u32, y.round() as u32)
+                })
+                .collect();
+
+            for (k, &(x1, y1)) in points.iter().enumerate() {
+                let (x2, y2) = points[(k + 1) % n];
+                match series.color {
+                    Some(color) => canvas.line_colored(x1, y1, x2, y2, rgb_to_pixelcolor(&color)),
+                    None => canvas.line(x1, y1, x2, y2),
+                }
+            }
+        }
+
+        // number the spokes just past their ends
+        for spoke in 0..n {
+            let (x, y) = self.point(spoke, radius + MARGIN / 2.0);
+            canvas.text(
+                x.round() as u32,
+                y.round() as u32,
+                4,
+                &(spoke + 1).to_string(),
+            );
+        }
+
+        canvas
+    }
+}
+
+impl Display for RadarChart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for row in self.canvas().rows() {
+            writeln!(f, "{}", row.replace(' ', "\u{2800}"))?;
+        }
+
+        let dimensions: Vec<String> = self
+            .dimensions
+            .iter()
+            .enumerate()
+            .map(|(k, name)| format!("{} {}", k + 1, name))
+            .collect();
+        writeln!(f, "{}", dimensions.join("  "))?;
+
+        let series: Vec<String> = self
+            .series
+            .iter()
+            .map(|series| match series.color {
+                Some(color) => format!(
+                    "{} {}",
+                    "⣿".truecolor(color.r, color.g, color.b),
+                    series.name
+                ),
+                None => series.name.clone(),
+            })
+            .collect();
+        if !series.is_empty() {
+            writeln!(f, "{}", series.join("  "))?;
+        }
+
+        Ok(())
+    }
+}
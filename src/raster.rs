@@ -0,0 +1,236 @@
+//! Rasterizing rendered text into pixels, for exporting charts as images.
+//!
+//! Braille cells are drawn dot by dot, box drawing characters as lines, and the rest
+//! with a small built-in font, so labels stay readable without depending on a font file.
+
+use rgb::RGB8;
+
+/// Width of a character cell in pixels.
+pub const CELL_WIDTH: usize = 8;
+/// Height of a character cell in pixels.
+pub const CELL_HEIGHT: usize = 16;
+
+/// Color of the background.
+const BACKGROUND: RGB8 = RGB8 { r: 0, g: 0, b: 0 };
+/// Color of text printed without a color.
+const FOREGROUND: RGB8 = RGB8 {
+    r: 200,
+    g: 200,
+    b: 200,
+};
+
+/// Image with three bytes per pixel.
+pub struct Raster {
+    /// Width of the image in pixels.
+    width: usize,
+    /// Colors of the pixels, row by row from the top left corner.
+    pub pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// Rasterizes the text into an image of the given size in character cells, which is
+    /// expected to fit every line.
+    pub fn new(text: &str, columns: usize, lines: usize) -> Self {
+        let width = columns * CELL_WIDTH;
+        let height = lines * CELL_HEIGHT;
+        let mut raster = Self {
+            width,
+            pixels: [BACKGROUND.r, BACKGROUND.g, BACKGROUND.b].repeat(width * height),
+        };
+
+        for (row, line) in text.lines().enumerate().take(lines) {
+            let mut color = FOREGROUND;
+            let mut column = 0;
+            let mut chars = line.chars();
+
+            while let Some(c) = chars.next() {
+                if c == '\u{1b}' {
+                    let sequence: String = chars.by_ref().take_while(|&c| c != 'm').collect();
+                    color = sgr_color(&sequence, color);
+                    continue;
+                }
+                if column >= columns {
+                    break;
+                }
+                raster.draw_char(c, column, row, color);
+                column += 1;
+            }
+        }
+
+        raster
+    }
+
+    /// Draws a character into the given cell.
+    fn draw_char(&mut self, c: char, column: usize, row: usize, color: RGB8) {
+        let (x0, y0) = (column * CELL_WIDTH, row * CELL_HEIGHT);
+        let mut fill = |dx: usize, dy: usize, w: usize, h: usize| {
+            for y in y0 + dy..y0 + dy + h {
+                for x in x0 + dx..x0 + dx + w {
+                    let i = (y * self.width + x) * 3;
+                    self.pixels[i..i + 3].copy_from_slice(&[color.r, color.g, color.b]);
+                }
+            }
+        };
+
+        match c {
+            ' ' => {}
+            '\u{2800}'..='\u{28ff}' => {
+                const DOTS: [(usize, usize); 8] = [
+                    (0, 0),
+                    (0, 1),
+                    (0, 2),
+                    (1, 0),
+                    (1, 1),
+                    (1, 2),
+                    (0, 3),
+                    (1, 3),
+                ];
+                let bits = c as u32 - 0x2800;
+                for (bit, (dx, dy)) in DOTS.iter().enumerate() {
+                    if bits & (1 << bit) != 0 {
+                        fill(dx * 4, dy * 4, 3, 3);
+                    }
+                }
+            }
+            '█' => fill(0, 0, CELL_WIDTH, CELL_HEIGHT),
+            '…' => {
+                for x in [0, 3, 6] {
+                    fill(x, 12, 1, 1);
+                }
+            }
+            _ => match box_arms(c) {
+                Some((up, down, left, right)) => {
+                    let (mx, my) = (CELL_WIDTH / 2, CELL_HEIGHT / 2);
+                    if up {
+                        fill(mx, 0, 1, my + 1);
+                    }
+                    if down {
+                        fill(mx, my, 1, CELL_HEIGHT - my);
+                    }
+                    if left {
+                        fill(0, my, mx + 1, 1);
+                    }
+                    if right {
+                        fill(mx, my, CELL_WIDTH - mx, 1);
+                    }
+                }
+                None => {
+                    // scale the 3x5 glyph up twice, leaving room around it
+                    for (y, bits) in glyph(c).iter().enumerate() {
+                        for x in 0..3 {
+                            if bits & (0b100 >> x) != 0 {
+                                fill(1 + x * 2, 3 + y * 2, 2, 2);
+                            }
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Returns the foreground color set by a select graphic rendition sequence, without the
+/// leading escape and the trailing `m`.
+fn sgr_color(sequence: &str, current: RGB8) -> RGB8 {
+    let params: Vec<&str> = sequence.trim_start_matches('[').split(';').collect();
+    let mut color = current;
+    let mut i = 0;
+
+    while i < params.len() {
+        match params[i] {
+            "" | "0" | "39" => color = FOREGROUND,
+            "38" if params.get(i + 1) == Some(&"2") && i + 4 < params.len() => {
+                let channel = |k: usize| params[i + k].parse().unwrap_or(0);
+                color = RGB8::new(channel(2), channel(3), channel(4));
+                i += 4;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    color
+}
+
+/// Returns whether a box drawing character has arms going up, down, left, and right.
+fn box_arms(c: char) -> Option<(bool, bool, bool, bool)> {
+    Some(match c {
+        '│' => (true, true, false, false),
+        '─' => (false, false, true, true),
+        '┌' => (false, true, false, true),
+        '┐' => (false, true, true, false),
+        '└' => (true, false, false, true),
+        '┘' => (true, false, true, false),
+        '├' => (true, true, false, true),
+        '┤' => (true, true, true, false),
+        '┬' => (false, true, true, true),
+        '┴' => (true, false, true, true),
+        '┼' => (true, true, true, true),
+        _ => return None,
+    })
+}
+
+/// Returns the rows of a 3x5 glyph for the character, the highest bit being the leftmost pixel.
+/// Lowercase letters are drawn as uppercase, and unknown characters as a checkerboard.
+fn glyph(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '+' => [0b000, 0b010, 0b111, 0b010, 0b000],
+        '%' => [0b101, 0b001, 0b010, 0b100, 0b101],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+        '(' => [0b001, 0b010, 0b010, 0b010, 0b001],
+        ')' => [0b100, 0b010, 0b010, 0b010, 0b100],
+        '[' => [0b011, 0b010, 0b010, 0b010, 0b011],
+        ']' => [0b110, 0b010, 0b010, 0b010, 0b110],
+        '<' => [0b001, 0b010, 0b100, 0b010, 0b001],
+        '>' => [0b100, 0b010, 0b001, 0b010, 0b100],
+        '=' => [0b000, 0b111, 0b000, 0b111, 0b000],
+        '_' => [0b000, 0b000, 0b000, 0b000, 0b111],
+        '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+        '?' => [0b110, 0b001, 0b010, 0b000, 0b010],
+        '\'' => [0b010, 0b010, 0b000, 0b000, 0b000],
+        '*' => [0b000, 0b101, 0b010, 0b101, 0b000],
+        '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+        'µ' => [0b000, 0b101, 0b101, 0b110, 0b100],
+        _ => [0b101, 0b010, 0b101, 0b010, 0b101],
+    }
+}
@@ -0,0 +1,70 @@
+//! Re-rendering charts at the size of the terminal whenever it is resized, so live
+//! dashboards aren't cut off after the window changes. Available with the `resize`
+//! feature on Unix platforms, where resizes are signaled with `SIGWINCH`.
+//!
+//! ```rust
+//! use textplots::resize;
+//! use textplots::{Chart, Plot, Shape};
+//!
+//! let wave = Shape::Continuous(Box::new(|x| x.sin()));
+//!
+//! resize::on_resize(|width, height| {
+//!     Chart::new(width, height, -5.0, 5.0).lineplot(&wave).display();
+//!     // returning true would keep re-rendering after every resize
+//!     false
+//! })
+//! .unwrap();
+//! ```
+
+use crate::{MIN_HEIGHT, MIN_WIDTH};
+use signal_hook::consts::SIGWINCH;
+use signal_hook::iterator::Signals;
+use std::io;
+use terminal_size::{terminal_size, Height, Width};
+
+/// Size of the canvas used when the size of the terminal is unknown, like when
+/// the output is redirected.
+const DEFAULT_SIZE: (u32, u32) = (120, 60);
+/// Terminal columns left for the y-axis labels next to the canvas.
+const LABEL_COLUMNS: u16 = 10;
+/// Terminal rows left for the x-axis labels and the prompt under the canvas.
+const LABEL_ROWS: u16 = 2;
+
+/// Returns the size in points of the largest canvas that fits in the terminal along with
+/// its labels, or a default size if the size of the terminal is unknown.
+pub fn canvas_size() -> (u32, u32) {
+    match terminal_size() {
+        Some((Width(columns), Height(rows))) => {
+            // a canvas takes one more character than its size in points suggests
+            let columns = columns.saturating_sub(LABEL_COLUMNS + 1) as u32;
+            let rows = rows.saturating_sub(LABEL_ROWS + 1) as u32;
+            ((columns * 2).max(MIN_WIDTH), (rows * 4).max(MIN_HEIGHT))
+        }
+        None => DEFAULT_SIZE,
+    }
+}
+
+/// Calls `render` with the size of the canvas that fits in the terminal, see
+/// [`canvas_size`], right away and again after every resize of the terminal,
+/// as long as it returns `true`.
+///
+/// Fails if the resize signal can't be listened to.
+pub fn on_resize<F>(mut render: F) -> io::Result<()>
+where
+    F: FnMut(u32, u32) -> bool,
+{
+    let (width, height) = canvas_size();
+    if !render(width, height) {
+        return Ok(());
+    }
+
+    let mut signals = Signals::new([SIGWINCH])?;
+    for _ in signals.forever() {
+        let (width, height) = canvas_size();
+        if !render(width, height) {
+            break;
+        }
+    }
+
+    Ok(())
+}
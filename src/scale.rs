@@ -6,6 +6,7 @@ use std::ops::Range;
 pub struct Scale {
     domain: Range<f32>,
     range: Range<f32>,
+    gap: Option<Range<f32>>,
 }
 
 impl Scale {
@@ -15,23 +16,72 @@ impl Scale {
     /// assert_eq!(-0.8, Scale::new(0_f32..10_f32, -1_f32..1_f32).linear(1.0));
     /// ```
     pub fn linear(&self, x: f32) -> f32 {
-        let p = (x - self.domain.start) / (self.domain.end - self.domain.start);
+        let (start, end) = (self.fold(self.domain.start), self.fold(self.domain.end));
+        let p = (self.fold(x) - start) / (end - start);
         let r = self.range.start + p * (self.range.end - self.range.start);
         r.max(self.range.start).min(self.range.end)
     }
 
+    /// Translates value from domain to range scale, without clamping values outside of the domain.
+    /// ```
+    /// # use textplots::scale::Scale;
+    /// assert_eq!(2.0, Scale::new(0_f32..10_f32, 0_f32..1_f32).linear_unclamped(20.0));
+    /// ```
+    pub fn linear_unclamped(&self, x: f32) -> f32 {
+        let (start, end) = (self.fold(self.domain.start), self.fold(self.domain.end));
+        let p = (self.fold(x) - start) / (end - start);
+        self.range.start + p * (self.range.end - self.range.start)
+    }
+
     /// Translates value from range to domain scale.
     /// ```
     /// # use textplots::scale::Scale;
     /// assert_eq!(5.5, Scale::new(0_f32..10_f32, -1_f32..1_f32).inv_linear(0.1));
     /// ```
     pub fn inv_linear(&self, i: f32) -> f32 {
+        let (start, end) = (self.fold(self.domain.start), self.fold(self.domain.end));
         let p = (i - self.range.start) / (self.range.end - self.range.start);
-        let d = self.domain.start + p * (self.domain.end - self.domain.start);
+        let d = self.unfold(start + p * (end - start));
         d.max(self.domain.start).min(self.domain.end)
     }
 
     pub fn new(domain: Range<f32>, range: Range<f32>) -> Self {
-        Scale { domain, range }
+        Scale {
+            domain,
+            range,
+            gap: None,
+        }
+    }
+
+    /// Cuts the gap out of the domain, so that the values on both sides of it lie next to each
+    /// other in the range, and the values inside of it are mapped onto its start.
+    /// ```
+    /// # use textplots::scale::Scale;
+    /// let scale = Scale::new(0_f32..100_f32, 0_f32..20_f32).with_break(10_f32..90_f32);
+    /// assert_eq!(10.0, scale.linear(10.0));
+    /// assert_eq!(10.0, scale.linear(50.0));
+    /// assert_eq!(15.0, scale.linear(95.0));
+    /// assert_eq!(95.0, scale.inv_linear(15.0));
+    /// ```
+    pub fn with_break(mut self, gap: Range<f32>) -> Self {
+        self.gap = Some(gap);
+        self
+    }
+
+    /// Translates the domain value into the domain with the gap cut out.
+    fn fold(&self, x: f32) -> f32 {
+        match &self.gap {
+            Some(gap) if x >= gap.end => x - (gap.end - gap.start),
+            Some(gap) if x > gap.start => gap.start,
+            _ => x,
+        }
+    }
+
+    /// Translates the value from the domain with the gap cut out back into the domain.
+    fn unfold(&self, x: f32) -> f32 {
+        match &self.gap {
+            Some(gap) if x > gap.start => x + (gap.end - gap.start),
+            _ => x,
+        }
     }
 }
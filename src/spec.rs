@@ -0,0 +1,367 @@
+//! Declarative chart descriptions, so chart configuration can live in config files.
+//!
+//! With the `serde` feature enabled, [`ChartSpec`] can be deserialized from any format supported
+//! by serde, such as JSON or TOML. Every field is optional.
+//!
+//! ```rust
+//! use textplots::spec::{ChartSpec, DisplayConfig, SeriesKind, SeriesSpec};
+//! use textplots::{BorderStyle, LabelFormat};
+//!
+//! let spec = ChartSpec {
+//!     width: 80,
+//!     height: 40,
+//!     caption: Some("requests per second".to_string()),
+//!     series: vec![SeriesSpec {
+//!         name: Some("api".to_string()),
+//!         kind: SeriesKind::Steps,
+//!         color: Some("#ff8800".to_string()),
+//!     }],
+//!     display: DisplayConfig {
+//!         border: Some(BorderStyle::Box),
+//!         y_label_format: Some(LabelFormat::Scientific(2)),
+//!         ..DisplayConfig::default()
+//!     },
+//!     ..ChartSpec::default()
+//! };
+//!
+//! let api = [(0.0, 120.0), (1.0, 180.0), (2.0, 150.0)];
+//! let chart = spec.render(&[&api]);
+//! assert!(chart.starts_with("requests per second\n"));
+//!
+//! # #[cfg(feature = "serde")]
+//! # {
+//! let spec: ChartSpec = serde_json::from_str(
+//!     r##"{
+//!         "width": 80,
+//!         "height": 40,
+//!         "caption": "requests per second",
+//!         "series": [{ "name": "api", "kind": "steps", "color": "#ff8800" }],
+//!         "display": { "border": "box", "y_label_format": { "scientific": 2 } }
+//!     }"##,
+//! )
+//! .unwrap();
+//! assert_eq!(spec.render(&[&api]), chart);
+//! # }
+//! ```
+
+use crate::{
+    AxisBuilder, AxisPosition, BorderBuilder, BorderStyle, CanvasBuilder, Chart, ColorPlot,
+    Interpolation, LabelBuilder, LabelFormat, LabelPosition, LineStyle, Plot, SeriesBuilder, Shape,
+    ShapeStyleBuilder, StepStyle, TextBuilder, TickDisplay, TickDisplayBuilder,
+};
+use rgb::RGB8;
+
+/// How the points of a series are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum SeriesKind {
+    /// Points connected with lines, see `Shape::Lines`.
+    #[default]
+    Lines,
+    /// Points of a scatter plot, see `Shape::Points`.
+    Points,
+    /// Points connected in step fashion, see `Shape::Steps`.
+    Steps,
+    /// Points represented with bars, see `Shape::Bars`.
+    Bars,
+}
+
+/// Description of a plotted series, the data itself being given when rendering.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SeriesSpec {
+    /// Name of the series, see `SeriesBuilder::series_name`.
+    pub name: Option<String>,
+    /// How the points are drawn.
+    pub kind: SeriesKind,
+    /// Color of the series, either a name or `#rrggbb`, see [`parse_color`].
+    pub color: Option<String>,
+}
+
+/// Display preferences of a chart, for tools persisting the choices of their users.
+/// Preferences that are not given keep the defaults of the chart.
+///
+/// ```rust
+/// use textplots::spec::DisplayConfig;
+/// use textplots::{BorderStyle, Chart, LabelFormat, Plot, Shape};
+///
+/// let config = DisplayConfig {
+///     border: Some(BorderStyle::Box),
+///     y_label_format: Some(LabelFormat::Percent),
+///     ..DisplayConfig::default()
+/// };
+///
+/// let shape = Shape::Lines(&[(0.0, 0.0), (1.0, 0.25)]);
+/// let mut chart = Chart::new_with_y_range(40, 8, 0.0, 1.0, 0.0, 0.25);
+/// let chart = config.apply(&mut chart).lineplot(&shape);
+///
+/// assert!(chart.to_string().contains("│ 25.0%"));
+/// ```
+#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct DisplayConfig {
+    /// X-axis style, see `AxisBuilder::x_axis_style`.
+    pub x_axis_style: Option<LineStyle>,
+    /// Y-axis style, see `AxisBuilder::y_axis_style`.
+    pub y_axis_style: Option<LineStyle>,
+    /// Position of the axes, see `AxisBuilder::axis_position`.
+    pub axis_position: Option<AxisPosition>,
+    /// Color of the axis lines, see [`parse_color`].
+    pub axis_color: Option<String>,
+    /// Border style, see `BorderBuilder::border_style`.
+    pub border: Option<BorderStyle>,
+    /// X-axis label format, see `LabelBuilder::x_label_format`.
+    pub x_label_format: Option<LabelFormat>,
+    /// Y-axis label format, see `LabelBuilder::y_label_format`.
+    pub y_label_format: Option<LabelFormat>,
+    /// Side of the y-axis labels, see `LabelBuilder::y_label_position`.
+    pub y_label_position: Option<LabelPosition>,
+    /// Color of the axis labels, see [`parse_color`].
+    pub label_color: Option<String>,
+    /// Density of the y-axis tick labels, see `TickDisplayBuilder::y_tick_display`.
+    pub y_tick_display: Option<TickDisplay>,
+    /// How steps connect consecutive points, see `ShapeStyleBuilder::step_style`.
+    pub step_style: Option<StepStyle>,
+    /// How lines connect consecutive points, see `ShapeStyleBuilder::interpolation`.
+    pub interpolation: Option<Interpolation>,
+    /// Character printed for empty canvas cells, see `CanvasBuilder::blank_char`.
+    pub blank_char: Option<char>,
+}
+
+impl DisplayConfig {
+    /// Applies the given preferences to the chart. Custom label formats are left out,
+    /// as their closures can't be shared.
+    pub fn apply<'a>(&self, chart: &'a mut Chart<'a>) -> &'a mut Chart<'a> {
+        let mut chart = chart;
+
+        if let Some(style) = self.x_axis_style {
+            chart = chart.x_axis_style(style);
+        }
+        if let Some(style) = self.y_axis_style {
+            chart = chart.y_axis_style(style);
+        }
+        if let Some(position) = self.axis_position {
+            chart = chart.axis_position(position);
+        }
+        if let Some(color) = self.axis_color.as_deref().and_then(parse_color) {
+            chart = chart.axis_color(color);
+        }
+        if let Some(style) = self.border {
+            chart = chart.border_style(style);
+        }
+        if let Some(format) = self
+            .x_label_format
+            .as_ref()
+            .and_then(LabelFormat::try_clone)
+        {
+            chart = chart.x_label_format(format);
+        }
+        if let Some(format) = self
+            .y_label_format
+            .as_ref()
+            .and_then(LabelFormat::try_clone)
+        {
+            chart = chart.y_label_format(format);
+        }
+        if let Some(position) = self.y_label_position {
+            chart = chart.y_label_position(position);
+        }
+        if let Some(color) = self.label_color.as_deref().and_then(parse_color) {
+            chart = chart.label_color(color);
+        }
+        if let Some(density) = self.y_tick_display {
+            chart = chart.y_tick_display(density);
+        }
+        if let Some(style) = self.step_style {
+            chart = chart.step_style(style);
+        }
+        if let Some(interpolation) = self.interpolation {
+            chart = chart.interpolation(interpolation);
+        }
+        if let Some(c) = self.blank_char {
+            chart = chart.blank_char(c);
+        }
+
+        chart
+    }
+}
+
+/// Description of a chart, rendered with the data of every series.
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ChartSpec {
+    /// Canvas width in points.
+    pub width: u32,
+    /// Canvas height in points.
+    pub height: u32,
+    /// X-axis range, fitted to the data if not given.
+    pub x_range: Option<(f32, f32)>,
+    /// Y-axis range, fitted to the data if not given.
+    pub y_range: Option<(f32, f32)>,
+    /// Text displayed above the chart.
+    pub caption: Option<String>,
+    /// Text displayed under the chart.
+    pub footer: Option<String>,
+    /// Whether summary statistics of every series are displayed under the chart.
+    pub stats: bool,
+    /// Series in the order their data is given.
+    pub series: Vec<SeriesSpec>,
+    /// Display preferences applied before the series are plotted.
+    pub display: DisplayConfig,
+}
+
+impl Default for ChartSpec {
+    fn default() -> Self {
+        Self {
+            width: 120,
+            height: 60,
+            x_range: None,
+            y_range: None,
+            caption: None,
+            footer: None,
+            stats: false,
+            series: Vec::new(),
+            display: DisplayConfig::default(),
+        }
+    }
+}
+
+impl ChartSpec {
+    /// Renders the chart with the points of every series, given in the order of `series`.
+    /// Data without a series description is drawn as lines, and descriptions without data
+    /// are left out. A size too small for a chart is raised to the minimum, see `Chart::new`.
+    pub fn render(&self, data: &[&[(f32, f32)]]) -> String {
+        let shapes: Vec<Shape> = data
+            .iter()
+            .enumerate()
+            .map(|(k, points)| {
+                match self
+                    .series
+                    .get(k)
+                    .map(|series| series.kind)
+                    .unwrap_or_default()
+                {
+                    SeriesKind::Lines => Shape::Lines(points),
+                    SeriesKind::Points => Shape::Points(points),
+                    SeriesKind::Steps => Shape::Steps(points),
+                    SeriesKind::Bars => Shape::Bars(points),
+                }
+            })
+            .collect();
+
+        let (xmin, xmax) = self.x_range.unwrap_or_else(|| {
+            let xs = data
+                .iter()
+                .flat_map(|points| points.iter().map(|(x, _)| *x));
+            let (min, max) = xs
+                .filter(|x| x.is_finite())
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), x| {
+                    (min.min(x), max.max(x))
+                });
+            if min < max {
+                (min, max)
+            } else if min.is_finite() {
+                (min - 1.0, min + 1.0)
+            } else {
+                (-10.0, 10.0)
+            }
+        });
+
+        let mut chart = match self.y_range {
+            Some((ymin, ymax)) => {
+                Chart::new_with_y_range(self.width, self.height, xmin, xmax, ymin, ymax)
+            }
+            None => Chart::new(self.width, self.height, xmin, xmax),
+        };
+        let mut chart = self.display.apply(&mut chart);
+
+        for (k, shape) in shapes.iter().enumerate() {
+            let series = self.series.get(k);
+            chart = match series
+                .and_then(|s| s.color.as_deref())
+                .and_then(parse_color)
+            {
+                Some(color) => chart.linecolorplot(shape, color),
+                None => chart.lineplot(shape),
+            };
+            if let Some(name) = series.and_then(|s| s.name.as_deref()) {
+                chart = chart.series_name(name);
+            }
+        }
+
+        if let Some(caption) = &self.caption {
+            chart = chart.caption(caption);
+        }
+        if let Some(footer) = &self.footer {
+            chart = chart.footer(footer);
+        }
+        chart.with_stats(self.stats).render()
+    }
+}
+
+/// Parses a color given as `#rrggbb`, `#rgb`, or one of the names `black`, `red`, `green`,
+/// `yellow`, `blue`, `magenta`, `cyan`, `white`, `gray`, `orange`, and `purple`.
+///
+/// ```rust
+/// use rgb::RGB8;
+/// use textplots::spec::parse_color;
+///
+/// assert_eq!(parse_color("#ff8800"), Some(RGB8::new(255, 136, 0)));
+/// assert_eq!(parse_color("#f80"), Some(RGB8::new(255, 136, 0)));
+/// assert_eq!(parse_color("Red"), Some(RGB8::new(255, 0, 0)));
+/// assert_eq!(parse_color("chartreuse"), None);
+/// ```
+pub fn parse_color(text: &str) -> Option<RGB8> {
+    let text = text.trim();
+    if let Some(hex) = text.strip_prefix('#') {
+        if !hex.is_ascii() {
+            return None;
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        return match hex.len() {
+            6 => Some(RGB8::new(
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            )),
+            3 => Some(RGB8::new(
+                channel(&hex[0..1])? * 17,
+                channel(&hex[1..2])? * 17,
+                channel(&hex[2..3])? * 17,
+            )),
+            _ => None,
+        };
+    }
+
+    let (r, g, b) = match text.to_ascii_lowercase().as_str() {
+        "black" => (0, 0, 0),
+        "red" => (255, 0, 0),
+        "green" => (0, 200, 0),
+        "yellow" => (255, 255, 0),
+        "blue" => (0, 0, 255),
+        "magenta" => (255, 0, 255),
+        "cyan" => (0, 255, 255),
+        "white" => (255, 255, 255),
+        "gray" | "grey" => (128, 128, 128),
+        "orange" => (255, 136, 0),
+        "purple" => (128, 0, 128),
+        _ => return None,
+    };
+    Some(RGB8::new(r, g, b))
+}
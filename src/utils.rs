@@ -2,6 +2,9 @@
 //!
 //! Merely a bunch of functions hanging around while the library API is taking shape.
 
+use crate::LabelFormat;
+use std::collections::{BTreeMap, HashMap};
+
 /// Transforms points into frequency distribution (for using in histograms).
 /// Values outside of [`min`, `max`] interval are ignored, and everything that
 /// falls into the specified interval is grouped into `bins` number of buckets of equal width.
@@ -32,3 +35,483 @@ pub fn histogram(data: &[(f32, f32)], min: f32, max: f32, bins: usize) -> Vec<(f
         .map(|(x, y)| ((min + (x as f32) * step), y as f32))
         .collect()
 }
+
+/// Same as [`histogram`], but every point contributes its weight (taken from `weights` at the same index)
+/// to its bucket instead of 1. Points without a corresponding weight are ignored.
+///
+/// ```
+/// # use textplots::utils::histogram_weighted;
+/// assert_eq!(vec![(0.0, 0.5), (5.0, 2.0)], histogram_weighted( &[ (0.0, 0.0), (9.0, 9.0), (10.0, 10.0) ], &[0.5, 2.0, 1.0], 0.0, 10.0, 2 ));
+/// ```
+pub fn histogram_weighted(
+    data: &[(f32, f32)],
+    weights: &[f32],
+    min: f32,
+    max: f32,
+    bins: usize,
+) -> Vec<(f32, f32)> {
+    let step = (max - min) / bins as f32;
+    let edges: Vec<f32> = (0..=bins).map(|i| min + (i as f32) * step).collect();
+
+    bucketize(data, weights.iter().copied(), &edges)
+}
+
+/// Transforms points into frequency distribution using explicit bucket edges, which don't have to be evenly spaced.
+/// Bucket `i` holds values in the `[edges[i], edges[i + 1])` interval, values outside of all buckets are ignored.
+/// Edges must be sorted in ascending order. Each bucket is reported at its left edge.
+///
+/// ```
+/// # use textplots::utils::histogram_with_edges;
+/// assert_eq!(vec![(1.0, 2.0), (10.0, 1.0)], histogram_with_edges( &[ (0.0, 1.0), (1.0, 5.0), (2.0, 50.0), (3.0, 500.0) ], &[1.0, 10.0, 100.0] ));
+/// ```
+pub fn histogram_with_edges(data: &[(f32, f32)], edges: &[f32]) -> Vec<(f32, f32)> {
+    bucketize(data, std::iter::repeat(1.0), edges)
+}
+
+/// Same as [`histogram_with_edges`], but every point contributes its weight (taken from `weights` at the same index)
+/// to its bucket instead of 1.
+///
+/// ```
+/// # use textplots::utils::histogram_weighted_with_edges;
+/// assert_eq!(vec![(0.0, 3.0), (1.0, 0.5)], histogram_weighted_with_edges( &[ (0.0, 0.5), (1.0, 0.7), (2.0, 1.5) ], &[1.0, 2.0, 0.5], &[0.0, 1.0, 2.0] ));
+/// ```
+pub fn histogram_weighted_with_edges(
+    data: &[(f32, f32)],
+    weights: &[f32],
+    edges: &[f32],
+) -> Vec<(f32, f32)> {
+    bucketize(data, weights.iter().copied(), edges)
+}
+
+/// Generates `bins + 1` logarithmically spaced bucket edges from `min` to `max`
+/// (both should be positive), for using with [`histogram_with_edges`].
+///
+/// ```
+/// # use textplots::utils::log_edges;
+/// let edges = log_edges(1.0, 1000.0, 3);
+/// assert_eq!(4, edges.len());
+/// assert!((edges[1] - 10.0).abs() < 1e-3 && (edges[2] - 100.0).abs() < 1e-2);
+/// ```
+pub fn log_edges(min: f32, max: f32, bins: usize) -> Vec<f32> {
+    let (lmin, lmax) = (min.ln(), max.ln());
+    let step = (lmax - lmin) / bins as f32;
+
+    (0..=bins)
+        .map(|i| match i {
+            0 => min,
+            i if i == bins => max,
+            i => (lmin + (i as f32) * step).exp(),
+        })
+        .collect()
+}
+
+/// Sums the weights of the points falling into every bucket between consecutive edges.
+fn bucketize(
+    data: &[(f32, f32)],
+    weights: impl Iterator<Item = f32>,
+    edges: &[f32],
+) -> Vec<(f32, f32)> {
+    let bins = edges.len().saturating_sub(1);
+    let mut output = vec![0.0; bins];
+
+    for (&(_x, y), weight) in data.iter().zip(weights) {
+        if bins == 0 || y < edges[0] || y >= edges[bins] {
+            continue;
+        }
+
+        let bucket_id = edges.partition_point(|&edge| edge <= y) - 1;
+        if bucket_id < output.len() {
+            output[bucket_id] += weight;
+        }
+    }
+
+    output
+        .into_iter()
+        .enumerate()
+        .map(|(i, total)| (edges[i], total))
+        .collect()
+}
+
+/// Fits a straight line to the points using ordinary least squares and returns its `(slope, intercept)`.
+/// If there are fewer than two distinct x values the line is undefined, and both values are NaN.
+///
+/// ```
+/// # use textplots::utils::linear_fit;
+/// assert_eq!((2.0, 1.0), linear_fit(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]));
+/// ```
+pub fn linear_fit(data: &[(f32, f32)]) -> (f32, f32) {
+    let n = data.len() as f64;
+    let mean_x = data.iter().map(|&(x, _)| x as f64).sum::<f64>() / n;
+    let mean_y = data.iter().map(|&(_, y)| y as f64).sum::<f64>() / n;
+
+    let (mut sxy, mut sxx) = (0.0, 0.0);
+    for &(x, y) in data.iter() {
+        let dx = x as f64 - mean_x;
+        sxy += dx * (y as f64 - mean_y);
+        sxx += dx * dx;
+    }
+
+    if sxx == 0.0 || !sxx.is_finite() {
+        return (f32::NAN, f32::NAN);
+    }
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+
+    (slope as f32, intercept as f32)
+}
+
+/// Replaces every y value with the running total of y values up to and including it.
+///
+/// ```
+/// # use textplots::utils::cumsum;
+/// assert_eq!(vec![(0.0, 1.0), (1.0, 3.0), (2.0, 6.0)], cumsum(&[(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]));
+/// ```
+pub fn cumsum(data: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut total = 0.0;
+
+    data.iter()
+        .map(|&(x, y)| {
+            total += y;
+            (x, total)
+        })
+        .collect()
+}
+
+/// Rescales y values linearly so that the smallest becomes 0 and the largest becomes 1.
+/// If all y values are equal, they are all mapped to 0.
+///
+/// ```
+/// # use textplots::utils::normalize_minmax;
+/// assert_eq!(vec![(0.0, 0.0), (1.0, 0.25), (2.0, 1.0)], normalize_minmax(&[(0.0, 10.0), (1.0, 20.0), (2.0, 50.0)]));
+/// ```
+pub fn normalize_minmax(data: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let min = data.iter().map(|&(_, y)| y).fold(f32::INFINITY, f32::min);
+    let max = data
+        .iter()
+        .map(|&(_, y)| y)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let span = max - min;
+
+    data.iter()
+        .map(|&(x, y)| {
+            if span > 0.0 {
+                (x, (y - min) / span)
+            } else {
+                (x, 0.0)
+            }
+        })
+        .collect()
+}
+
+/// Standardizes y values by subtracting their mean and dividing by their (population) standard deviation.
+/// If all y values are equal, they are all mapped to 0.
+///
+/// ```
+/// # use textplots::utils::zscore;
+/// assert_eq!(vec![(0.0, -1.0), (1.0, 1.0)], zscore(&[(0.0, 2.0), (1.0, 4.0)]));
+/// ```
+pub fn zscore(data: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let n = data.len() as f32;
+    let mean = data.iter().map(|&(_, y)| y).sum::<f32>() / n;
+    let variance = data.iter().map(|&(_, y)| (y - mean).powi(2)).sum::<f32>() / n;
+    let std_dev = variance.sqrt();
+
+    data.iter()
+        .map(|&(x, y)| {
+            if std_dev > 0.0 {
+                (x, (y - mean) / std_dev)
+            } else {
+                (x, 0.0)
+            }
+        })
+        .collect()
+}
+
+/// Sums values sharing the same category. Categories are returned in order of their first appearance.
+///
+/// ```
+/// # use textplots::utils::group_by_sum;
+/// let data = [("/a".to_string(), 1.0), ("/b".to_string(), 2.0), ("/a".to_string(), 3.0)];
+/// assert_eq!(vec![("/a".to_string(), 4.0), ("/b".to_string(), 2.0)], group_by_sum(&data));
+/// ```
+pub fn group_by_sum(data: &[(String, f32)]) -> Vec<(String, f32)> {
+    group_by(data)
+        .into_iter()
+        .map(|(key, values)| (key, values.iter().sum()))
+        .collect()
+}
+
+/// Averages values sharing the same category. Categories are returned in order of their first appearance.
+///
+/// ```
+/// # use textplots::utils::group_by_mean;
+/// let data = [("/a".to_string(), 1.0), ("/b".to_string(), 2.0), ("/a".to_string(), 3.0)];
+/// assert_eq!(vec![("/a".to_string(), 2.0), ("/b".to_string(), 2.0)], group_by_mean(&data));
+/// ```
+pub fn group_by_mean(data: &[(String, f32)]) -> Vec<(String, f32)> {
+    group_by(data)
+        .into_iter()
+        .map(|(key, values)| {
+            let mean = values.iter().sum::<f32>() / values.len() as f32;
+            (key, mean)
+        })
+        .collect()
+}
+
+/// Turns categorical values into points for `Shape::Bars`, one bar of unit width per category,
+/// with category `i` spanning the `[i, i + 1)` interval on the x-axis.
+/// Use a chart with x range from 0 to the number of categories, along with [`category_labels`].
+///
+/// ```
+/// # use textplots::utils::category_bars;
+/// let groups = [("/a".to_string(), 4.0), ("/b".to_string(), 2.0)];
+/// assert_eq!(vec![(0.0, 4.0), (1.0, 4.0), (2.0, 2.0)], category_bars(&groups));
+/// ```
+pub fn category_bars(groups: &[(String, f32)]) -> Vec<(f32, f32)> {
+    // every bar takes its height from the point at its right edge
+    let mut output: Vec<(f32, f32)> = groups
+        .iter()
+        .enumerate()
+        .map(|(i, (_, y))| ((i + 1) as f32, *y))
+        .collect();
+
+    if let Some((_, y)) = groups.first() {
+        output.insert(0, (0.0, *y));
+    }
+
+    output
+}
+
+/// Creates a label format showing the name of the category under the given x value,
+/// matching the layout of [`category_bars`].
+///
+/// ```rust
+/// use textplots::{utils, Chart, LabelBuilder, Plot, Shape};
+///
+/// let requests = [
+///     ("/index".to_string(), 1.0),
+///     ("/login".to_string(), 1.0),
+///     ("/index".to_string(), 1.0),
+/// ];
+/// let groups = utils::group_by_sum(&requests);
+/// let bars = utils::category_bars(&groups);
+///
+/// Chart::new_with_y_range(120, 60, 0.0, groups.len() as f32, 0.0, 3.0)
+///     .lineplot(&Shape::Bars(&bars))
+///     .x_label_format(utils::category_labels(&groups))
+///     .display();
+/// ```
+pub fn category_labels(groups: &[(String, f32)]) -> LabelFormat {
+    let names: Vec<String> = groups.iter().map(|(name, _)| name.clone()).collect();
+
+    LabelFormat::Custom(Box::new(move |x| {
+        if x < 0.0 || x > names.len() as f32 || names.is_empty() {
+            return String::new();
+        }
+        let i = (x.floor() as usize).min(names.len() - 1);
+        names[i].clone()
+    }))
+}
+
+/// Collects values by category, preserving the order in which categories first appear.
+fn group_by(data: &[(String, f32)]) -> Vec<(String, Vec<f32>)> {
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    let mut output: Vec<(String, Vec<f32>)> = Vec::new();
+
+    for (key, value) in data.iter() {
+        match index.get(key.as_str()) {
+            Some(&i) => output[i].1.push(*value),
+            None => {
+                index.insert(key, output.len());
+                output.push((key.clone(), vec![*value]));
+            }
+        }
+    }
+
+    output
+}
+
+/// Specifies how values falling into the same interval are combined by [`resample`].
+#[derive(Clone, Copy)]
+pub enum Aggregate {
+    /// Average of the values.
+    Mean,
+    /// Smallest value.
+    Min,
+    /// Largest value.
+    Max,
+    /// Sum of the values.
+    Sum,
+    /// Value of the earliest point.
+    First,
+    /// Value of the latest point.
+    Last,
+}
+
+/// Buckets irregularly spaced points into fixed intervals of x (aligned to multiples of `interval`),
+/// combining the values in every bucket as specified by `aggregate`.
+/// Every bucket is reported at its start, empty buckets are omitted, and the output is sorted by x.
+///
+/// ```
+/// # use textplots::utils::{resample, Aggregate};
+/// let events = [(0.5, 1.0), (1.2, 4.0), (1.7, 2.0), (3.1, 5.0)];
+/// assert_eq!(vec![(0.0, 1.0), (1.0, 3.0), (3.0, 5.0)], resample(&events, 1.0, Aggregate::Mean));
+/// assert_eq!(vec![(0.0, 1.0), (1.0, 2.0), (3.0, 5.0)], resample(&events, 1.0, Aggregate::Last));
+/// ```
+pub fn resample(data: &[(f32, f32)], interval: f32, aggregate: Aggregate) -> Vec<(f32, f32)> {
+    let mut sorted: Vec<(f32, f32)> = data
+        .iter()
+        .copied()
+        .filter(|(x, _)| x.is_finite())
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut buckets: BTreeMap<i64, Vec<f32>> = BTreeMap::new();
+    for (x, y) in sorted {
+        let bucket_id = (x / interval).floor() as i64;
+        buckets.entry(bucket_id).or_default().push(y);
+    }
+
+    buckets
+        .into_iter()
+        .map(|(bucket_id, values)| {
+            let y = match aggregate {
+                Aggregate::Mean => values.iter().sum::<f32>() / values.len() as f32,
+                Aggregate::Min => values.iter().copied().fold(f32::INFINITY, f32::min),
+                Aggregate::Max => values.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+                Aggregate::Sum => values.iter().sum(),
+                Aggregate::First => values[0],
+                Aggregate::Last => values[values.len() - 1],
+            };
+            (bucket_id as f32 * interval, y)
+        })
+        .collect()
+}
+
+/// Computes the autocorrelation of evenly spaced samples for lags from 0 up to `max_lag`
+/// (or the number of samples minus one, if smaller), returning `(lag, correlation)` pairs.
+/// Correlations are normalized so that lag 0 is always 1. For constant samples the correlation is undefined, and NaN is returned.
+///
+/// ```
+/// # use textplots::utils::autocorrelation;
+/// let acf = autocorrelation(&[1.0, -1.0, 1.0, -1.0], 2);
+/// assert_eq!(vec![(0.0, 1.0), (1.0, -0.75), (2.0, 0.5)], acf);
+/// ```
+pub fn autocorrelation(samples: &[f32], max_lag: usize) -> Vec<(f32, f32)> {
+    let n = samples.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mean = samples.iter().sum::<f32>() / n as f32;
+    let deviations: Vec<f32> = samples.iter().map(|s| s - mean).collect();
+    let variance: f32 = deviations.iter().map(|d| d * d).sum();
+
+    (0..=max_lag.min(n - 1))
+        .map(|lag| {
+            let covariance: f32 = deviations
+                .iter()
+                .zip(deviations.iter().skip(lag))
+                .map(|(a, b)| a * b)
+                .sum();
+            let correlation = if variance > 0.0 {
+                covariance / variance
+            } else {
+                f32::NAN
+            };
+            (lag as f32, correlation)
+        })
+        .collect()
+}
+
+/// Renders the values as a single line of block characters, one per value, scaled between
+/// the smallest and the largest value. Values that are not finite are shown as spaces.
+///
+/// ```
+/// # use textplots::utils::sparkline;
+/// assert_eq!("▁▂▄█▄ ▁", sparkline(&[0.0, 1.0, 3.0, 7.0, 3.0, f32::NAN, 0.0]));
+/// ```
+pub fn sparkline(values: &[f32]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let (min, max) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    let span = max - min;
+
+    values
+        .iter()
+        .map(|&v| {
+            if !v.is_finite() {
+                ' '
+            } else if span > 0.0 {
+                BLOCKS[((v - min) / span * 7.0).round() as usize]
+            } else {
+                BLOCKS[3]
+            }
+        })
+        .collect()
+}
+
+/// Pairs audio samples with their time in seconds, for plotting PCM buffers with
+/// `SamplingBuilder::envelope`, which draws them the way audio editors do.
+///
+/// ```
+/// # use textplots::utils::waveform;
+/// assert_eq!(vec![(0.0, 0.5), (0.25, -0.5), (0.5, 0.0)], waveform(&[0.5, -0.5, 0.0], 4.0));
+/// ```
+pub fn waveform(samples: &[f32], sample_rate: f32) -> Vec<(f32, f32)> {
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &sample)| (i as f32 / sample_rate, sample))
+        .collect()
+}
+
+/// Computes the single-sided amplitude spectrum of evenly spaced samples,
+/// returning `(frequency, magnitude)` pairs from 0 up to the Nyquist frequency.
+/// A sine wave of amplitude `A` shows up as a peak of height `A` at its frequency.
+///
+/// Requires the `rustfft` feature.
+///
+/// ```
+/// # use textplots::utils::spectrum;
+/// let samples: Vec<f32> = (0..64)
+///     .map(|i| (2.0 * std::f32::consts::PI * 8.0 * i as f32 / 64.0).sin())
+///     .collect();
+/// let peak = spectrum(&samples, 64.0)
+///     .into_iter()
+///     .max_by(|a, b| a.1.total_cmp(&b.1))
+///     .unwrap();
+/// assert_eq!(8.0, peak.0);
+/// assert!((peak.1 - 1.0).abs() < 1e-3);
+/// ```
+#[cfg(feature = "rustfft")]
+pub fn spectrum(samples: &[f32], sample_rate: f32) -> Vec<(f32, f32)> {
+    use rustfft::{num_complex::Complex, FftPlanner};
+
+    let n = samples.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut buffer: Vec<Complex<f32>> = samples.iter().map(|&s| Complex::new(s, 0.0)).collect();
+    FftPlanner::new().plan_fft_forward(n).process(&mut buffer);
+
+    buffer
+        .iter()
+        .take(n / 2 + 1)
+        .enumerate()
+        .map(|(k, c)| {
+            let scale = if k == 0 || 2 * k == n { 1.0 } else { 2.0 };
+            (
+                k as f32 * sample_rate / n as f32,
+                scale * c.norm() / n as f32,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,89 @@
+//! Conversion of typed values into the plain numbers plotted by the chart.
+//!
+//! Anything implementing [`IntoPlotValue`] can be turned into points with [`points`],
+//! so code working with its own number types doesn't have to strip them by hand.
+//! With the `uom` feature, [uom](https://crates.io/crates/uom) quantities are plotted
+//! in their base SI units.
+//!
+//! ```rust
+//! use textplots::value::points;
+//! use textplots::{Chart, Plot, Shape};
+//!
+//! let counts: Vec<(u32, u64)> = vec![(1, 3), (2, 8), (3, 5), (4, 12)];
+//! let points = points(counts);
+//!
+//! Chart::new(120, 60, 1.0, 4.0)
+//!     .lineplot(&Shape::Lines(&points))
+//!     .display();
+//! ```
+
+/// A value that can be plotted as a number.
+pub trait IntoPlotValue {
+    /// Returns the value as a number.
+    fn into_plot_value(self) -> f32;
+}
+
+macro_rules! impl_into_plot_value {
+    ($($t:ty),*) => {
+        $(
+            impl IntoPlotValue for $t {
+                fn into_plot_value(self) -> f32 {
+                    self as f32
+                }
+            }
+        )*
+    };
+}
+
+impl_into_plot_value!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Plots a duration in seconds, to be labeled with `LabelFormat::Duration`.
+impl IntoPlotValue for std::time::Duration {
+    fn into_plot_value(self) -> f32 {
+        self.as_secs_f32()
+    }
+}
+
+/// Plots a quantity in the base SI units of its dimension, like meters or seconds.
+/// Quantities meant to be plotted in other units have to be converted with `get` first.
+///
+/// ```rust
+/// use textplots::value::points;
+/// use uom::si::f32::{Length, Time};
+/// use uom::si::length::kilometer;
+/// use uom::si::time::second;
+///
+/// let track = vec![
+///     (Time::new::<second>(0.0), Length::new::<kilometer>(0.0)),
+///     (Time::new::<second>(10.0), Length::new::<kilometer>(0.5)),
+/// ];
+/// assert_eq!(vec![(0.0, 0.0), (10.0, 500.0)], points(track));
+/// ```
+#[cfg(feature = "uom")]
+impl<D, U, V> IntoPlotValue for uom::si::Quantity<D, U, V>
+where
+    D: uom::si::Dimension + ?Sized,
+    U: uom::si::Units<V> + ?Sized,
+    V: uom::num::Num + uom::Conversion<V> + IntoPlotValue,
+{
+    fn into_plot_value(self) -> f32 {
+        self.value.into_plot_value()
+    }
+}
+
+/// Returns the pairs of values as points.
+///
+/// ```
+/// # use textplots::value::points;
+/// assert_eq!(vec![(1.0, 0.5), (2.0, 1.5)], points(vec![(1u8, 0.5f64), (2, 1.5)]));
+/// ```
+pub fn points<X, Y, I>(data: I) -> Vec<(f32, f32)>
+where
+    X: IntoPlotValue,
+    Y: IntoPlotValue,
+    I: IntoIterator<Item = (X, Y)>,
+{
+    data.into_iter()
+        .map(|(x, y)| (x.into_plot_value(), y.into_plot_value()))
+        .collect()
+}